@@ -0,0 +1,46 @@
+//! serializes a point as a two element `[x, y]` sequence instead of a struct with named fields.
+//! use it on a field with `#[serde(with = "point_index::compact")]`
+//! ```
+//! use point_index::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Player {
+//!     #[serde(with = "point_index::compact")]
+//!     pos: Point,
+//!     facing: Direction,
+//! }
+//!
+//! let player = Player { pos: Point::new(3, 4), facing: Direction::Left };
+//! let json = serde_json::to_string(&player).unwrap();
+//! assert_eq!(json, r#"{"pos":[3,4],"facing":"Left"}"#);
+//! assert_eq!(serde_json::from_str::<Player>(&json).unwrap(), player);
+//! ```
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::GenericPoint;
+
+/// writes the point as `[x, y]`
+pub fn serialize<T: Serialize, S: Serializer>(point: &GenericPoint<T>, serializer: S) -> Result<S::Ok, S::Error> {
+    (&point.x, &point.y).serialize(serializer)
+}
+
+/// reads a point from `[x, y]`
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<GenericPoint<T>, D::Error> {
+    <(T, T)>::deserialize(deserializer).map(GenericPoint::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction, Point, Rotation};
+
+    #[test]
+    fn default_representations_round_trip() {
+        let point = Point::new_isize(-2, 7);
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(json, r#"{"x":-2,"y":7}"#);
+        assert_eq!(serde_json::from_str::<Point>(&json).unwrap(), point);
+        let turns: Vec<Rotation> = serde_json::from_str(r#"["Clockwise","Half"]"#).unwrap();
+        assert_eq!(turns, [Rotation::Clockwise, Rotation::Half]);
+        assert_eq!(serde_json::to_string(&Direction::ALL).unwrap(), r#"["Up","Right","Down","Left"]"#);
+    }
+}
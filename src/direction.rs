@@ -0,0 +1,359 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul};
+use std::str::FromStr;
+use crate::{Point, DOWN, LEFT, RIGHT, UP};
+
+/// one of the four cardinal directions, for when matching on a direction reads better than
+/// comparing against the point constants. the variants are in clockwise order starting from up
+/// ```
+/// use point_index::*;
+/// let mut pos = Point::new(1, 1);
+/// for direction in Direction::ALL {
+///     pos += direction;
+/// }
+/// assert_eq!(pos, Point::new(1, 1));
+/// assert_eq!(Point::new(1, 1) + Direction::Up, Point::new(1, 0));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// all the directions in clockwise order starting from up
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// iterates over all the directions in clockwise order starting from up
+    pub fn iter() -> impl Iterator<Item = Direction> {
+        Direction::ALL.into_iter()
+    }
+
+    /// the direction after turning 90 degrees clockwise
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    /// assert_eq!(Direction::Left.turn_right(), Direction::Up);
+    /// ```
+    pub const fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    /// the direction after turning 90 degrees counter clockwise
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    /// ```
+    pub const fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    /// the direction pointing the other way
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Direction::Down.opposite(), Direction::Up);
+    /// ```
+    pub const fn opposite(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// the unit vector pointing in this direction
+    pub const fn to_point(self) -> Point {
+        match self {
+            Direction::Up => UP,
+            Direction::Right => RIGHT,
+            Direction::Down => DOWN,
+            Direction::Left => LEFT,
+        }
+    }
+
+    /// the direction a unit vector points in, returns None for anything that isn't one of the four
+    /// cardinal unit vectors
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Direction::from_point(LEFT), Some(Direction::Left));
+    /// assert_eq!(Direction::from_point(UP_LEFT), None);
+    /// ```
+    pub fn from_point(point: Point) -> Option<Direction> {
+        Direction::iter().find(|direction| direction.to_point() == point)
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Point {
+        direction.to_point()
+    }
+}
+
+impl TryFrom<Point> for Direction {
+    type Error = Point;
+
+    /// fails with the original point if it isn't a cardinal unit vector
+    fn try_from(point: Point) -> Result<Direction, Point> {
+        Direction::from_point(point).ok_or(point)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, other: Direction) -> Point {
+        self + other.to_point()
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, other: Direction) {
+        *self = *self + other;
+    }
+}
+
+impl Mul<isize> for Direction {
+    type Output = Point;
+
+    fn mul(self, other: isize) -> Point {
+        self.to_point() * other
+    }
+}
+
+/// the error returned when a character or string isn't a recognised direction
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParseDirectionError(());
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a direction like U, N, ^ or up")
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+/// parses the common single character encodings, `U/D/L/R`, `N/S/E/W` (in either case) and the
+/// arrows `^/v/</>`
+/// ```
+/// use point_index::*;
+/// assert_eq!(Direction::try_from('^'), Ok(Direction::Up));
+/// assert_eq!(Direction::try_from('e'), Ok(Direction::Right));
+/// assert!(Direction::try_from('x').is_err());
+/// ```
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(c: char) -> Result<Direction, ParseDirectionError> {
+        match c {
+            'U' | 'u' | 'N' | 'n' | '^' => Ok(Direction::Up),
+            'R' | 'r' | 'E' | 'e' | '>' => Ok(Direction::Right),
+            'D' | 'd' | 'S' | 's' | 'v' => Ok(Direction::Down),
+            'L' | 'l' | 'W' | 'w' | '<' => Ok(Direction::Left),
+            _ => Err(ParseDirectionError(())),
+        }
+    }
+}
+
+/// parses either a single character accepted by `TryFrom<char>` or a full name like `up` or
+/// `north`, ignoring case and surrounding whitespace
+/// ```
+/// use point_index::*;
+/// assert_eq!("North".parse(), Ok(Direction::Up));
+/// assert_eq!(" > ".parse(), Ok(Direction::Right));
+/// assert!("sideways".parse::<Direction>().is_err());
+/// ```
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Direction, ParseDirectionError> {
+        let s = s.trim();
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Direction::try_from(c);
+        }
+        match s.to_ascii_lowercase().as_str() {
+            "up" | "north" => Ok(Direction::Up),
+            "right" | "east" => Ok(Direction::Right),
+            "down" | "south" => Ok(Direction::Down),
+            "left" | "west" => Ok(Direction::Left),
+            _ => Err(ParseDirectionError(())),
+        }
+    }
+}
+
+/// the error returned when an instruction list contains something that isn't a direction followed
+/// by a count
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParseInstructionError(());
+
+impl fmt::Display for ParseInstructionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected instructions like R 4 or L3")
+    }
+}
+
+impl Error for ParseInstructionError {}
+
+/// how [`parse_instructions_with`] reads an instruction list, the default accepts everything
+/// [`parse_instructions`] does
+/// ```
+/// use point_index::*;
+/// let wires = InstructionFormat { separators: ",", words: false };
+/// assert_eq!(parse_instructions_with("R8,U5\n", &wires), Ok(vec![(Direction::Right, 8), (Direction::Up, 5)]));
+/// assert!(parse_instructions_with("R8 U5", &wires).is_err());
+/// assert!(parse_instructions_with("up 2", &wires).is_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct InstructionFormat {
+    /// the characters that can separate two instructions, any run of them counts as one separator
+    pub separators: &'static str,
+    /// whether directions can be written as words like `up` or `north`, otherwise only the single
+    /// characters of `TryFrom<char>` are accepted
+    pub words: bool,
+}
+
+impl Default for InstructionFormat {
+    /// whitespace, commas and semicolons as separators, and directions as characters or words
+    fn default() -> InstructionFormat {
+        InstructionFormat { separators: " \t\r\n,;", words: true }
+    }
+}
+
+/// parses a list of moves where each move is a direction followed by a count, like `"R 4\nU 3"`,
+/// `"R8,U5,L5"` or `"up 2; left 1"`. instructions can be separated by whitespace, commas or
+/// semicolons and the space between the direction and the count is optional. the direction can be
+/// anything Direction's FromStr accepts and the count can be negative. see
+/// [`parse_instructions_with`] to accept only one of these formats
+/// ```
+/// use point_index::*;
+/// let moves = parse_instructions("R 4\nU 3").unwrap();
+/// assert_eq!(moves, [(Direction::Right, 4), (Direction::Up, 3)]);
+/// let end = parse_instructions("R8,U5,L5,D3").unwrap()
+///     .into_iter()
+///     .fold(Point::ZERO, |pos, (direction, count)| pos + direction * count);
+/// assert_eq!(end, Point::new_isize(3, -2));
+/// assert!(parse_instructions("R 4 U").is_err());
+/// ```
+pub fn parse_instructions(input: &str) -> Result<Vec<(Direction, isize)>, ParseInstructionError> {
+    parse_instructions_with(input, &InstructionFormat::default())
+}
+
+/// like [`parse_instructions`] but only accepts the separators and direction spellings of format.
+/// whitespace around the whole input is always ignored so a trailing newline is fine
+pub fn parse_instructions_with(
+    input: &str,
+    format: &InstructionFormat,
+) -> Result<Vec<(Direction, isize)>, ParseInstructionError> {
+    let is_separator = |c: char| format.separators.contains(c);
+    let mut instructions = Vec::new();
+    let mut rest = input.trim().trim_start_matches(is_separator);
+    while let Some(first) = rest.chars().next() {
+        let direction_len = match rest.find(|c: char| !c.is_alphabetic()) {
+            _ if !format.words => first.len_utf8(),
+            Some(0) => first.len_utf8(),
+            Some(len) => len,
+            None => rest.len(),
+        };
+        let (direction, tail) = rest.split_at(direction_len);
+        let direction = direction.parse().map_err(|_| ParseInstructionError(()))?;
+        let tail = tail.trim_start_matches([' ', '\t']);
+        let count_len = tail
+            .char_indices()
+            .find(|&(i, c)| !(c.is_ascii_digit() || (i == 0 && (c == '-' || c == '+'))))
+            .map_or(tail.len(), |(i, _)| i);
+        let (count, tail) = tail.split_at(count_len);
+        let count = count.parse().map_err(|_| ParseInstructionError(()))?;
+        instructions.push((direction, count));
+        let next = tail.trim_start_matches(is_separator);
+        if next.len() == tail.len() && !next.is_empty() {
+            return Err(ParseInstructionError(()));
+        }
+        rest = next;
+    }
+    Ok(instructions)
+}
+
+/// parses a compact path like `"UULDDRR"` or `"^^<vv>>"` into its moves, whitespace is ignored.
+/// the whole string is checked up front so the returned iterator can't fail halfway through
+/// ```
+/// use point_index::*;
+/// let net: Point = parse_path("UULDDRR").unwrap().map(Point::from).sum();
+/// assert_eq!(net, RIGHT);
+/// assert!(parse_path("UUX").is_err());
+/// ```
+pub fn parse_path(path: &str) -> Result<impl Iterator<Item = Direction> + '_, ParseDirectionError> {
+    let moves = path.chars().filter(|c| !c.is_whitespace());
+    for c in moves.clone() {
+        Direction::try_from(c)?;
+    }
+    Ok(moves.map(|c| Direction::try_from(c).unwrap()))
+}
+
+/// follows the moves from start and yields every position visited after each step, the start
+/// itself is not included
+/// ```
+/// use point_index::*;
+/// let visited: Vec<Point> = trace_path(Point::new(0, 0), parse_path("RRD").unwrap()).collect();
+/// assert_eq!(visited, [Point::new(1, 0), Point::new(2, 0), Point::new(2, 1)]);
+/// ```
+pub fn trace_path<I: IntoIterator<Item = Direction>>(start: Point, moves: I) -> impl Iterator<Item = Point> {
+    moves.into_iter().scan(start, |pos, direction| {
+        *pos += direction;
+        Some(*pos)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for direction in Direction::iter() {
+            assert_eq!(Direction::try_from(Point::from(direction)), Ok(direction));
+        }
+        assert_eq!(Direction::try_from(DOWN * 2), Err(DOWN * 2));
+    }
+    #[test]
+    fn turning_matches_rotation() {
+        for direction in Direction::iter() {
+            assert_eq!(direction.turn_right().to_point(), direction.to_point().rotate_cw());
+            assert_eq!(direction.turn_left().to_point(), direction.to_point().rotate_ccw());
+            assert_eq!(direction.opposite().to_point(), -direction.to_point());
+            assert_eq!(direction.turn_left().turn_right(), direction);
+        }
+    }
+    #[test]
+    fn parse_encodings() {
+        let parsed: Result<Vec<Direction>, _> = "^>v<".chars().map(Direction::try_from).collect();
+        assert_eq!(parsed, Ok(Direction::ALL.to_vec()));
+        assert_eq!("".parse::<Direction>(), Err(ParseDirectionError(())));
+        assert_eq!("WEST".parse(), Ok(Direction::Left));
+    }
+    #[test]
+    fn trace_returns_to_start() {
+        let start = Point::new(3, 3);
+        let visited: Vec<Point> = trace_path(start, parse_path("^ > v <").unwrap()).collect();
+        assert_eq!(visited.len(), 4);
+        assert_eq!(visited.last(), Some(&start));
+    }
+    #[test]
+    fn instruction_formats() {
+        let expected = vec![(Direction::Right, 4), (Direction::Left, -2), (Direction::Down, 10)];
+        assert_eq!(parse_instructions("R 4\nL -2\nD 10\n"), Ok(expected.clone()));
+        assert_eq!(parse_instructions("R4,L-2,D10"), Ok(expected.clone()));
+        assert_eq!(parse_instructions("east 4; west -2; south 10"), Ok(expected.clone()));
+        assert_eq!(parse_instructions("> 4 < -2 v 10"), Ok(expected.clone()));
+        let lines = InstructionFormat { separators: "\n", words: false };
+        assert_eq!(parse_instructions_with("R 4\nL -2\nD 10\n", &lines), Ok(expected));
+        assert!(parse_instructions_with("R 4, L -2", &lines).is_err());
+        assert!(parse_instructions_with("east 4", &lines).is_err());
+        assert_eq!(parse_instructions(""), Ok(vec![]));
+        assert_eq!(parse_instructions("R4x"), Err(ParseInstructionError(())));
+    }
+    #[test]
+    fn scaled_moves() {
+        let arr = [[0, 1, 2, 3]; 2];
+        assert_eq!(arr[Point::new(0, 1) + Direction::Right * 3], 3);
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::hash_map;
+use crate::{Grid, Point, SparseGrid};
+
+impl<T> SparseGrid<T> {
+    /// the stored cell at point for in place changes, an entry is vacant if nothing was written to
+    /// the cell yet even though it reads as the default
+    /// ```
+    /// use point_index::*;
+    /// let mut visits = SparseGrid::new(0);
+    /// for point in [Point::ZERO, Point::ONE, Point::ZERO] {
+    ///     visits.entry(point).and_modify(|n| *n += 1).or_insert(1);
+    /// }
+    /// assert_eq!(visits[Point::ZERO], 2);
+    /// assert_eq!(visits.len(), 2);
+    /// ```
+    pub fn entry(&mut self, point: Point) -> Entry<'_, T> {
+        Entry { point, slot: Slot::Sparse(self.cells.entry(point)) }
+    }
+}
+
+impl<T> Grid<Option<T>> {
+    /// the cell at point for in place changes, an entry is vacant if the cell holds None.
+    /// returns None if the point is outside the grid
+    /// ```
+    /// use point_index::*;
+    /// let mut first_seen: Grid<Option<usize>> = Grid::new(3, 3, None);
+    /// for (step, point) in [Point::ONE, Point::ZERO, Point::ONE].into_iter().enumerate() {
+    ///     first_seen.entry(point).unwrap().or_insert(step);
+    /// }
+    /// assert_eq!(first_seen[Point::ONE], Some(0));
+    /// assert_eq!(first_seen[Point::ZERO], Some(1));
+    /// assert!(first_seen.entry(Point::new(3, 0)).is_none());
+    /// ```
+    pub fn entry(&mut self, point: Point) -> Option<Entry<'_, T>> {
+        let i = self.flat_index(point)?;
+        Some(Entry { point, slot: Slot::Dense(&mut self.data[i]) })
+    }
+}
+
+/// a cell of a [`SparseGrid`] or of a `Grid<Option<T>>` that may or may not hold a value, created
+/// by their entry methods. it mirrors the entry of a `HashMap`
+#[derive(Debug)]
+pub struct Entry<'a, T> {
+    point: Point,
+    slot: Slot<'a, T>,
+}
+
+#[derive(Debug)]
+enum Slot<'a, T> {
+    Sparse(hash_map::Entry<'a, Point, T>),
+    Dense(&'a mut Option<T>),
+}
+
+impl<'a, T> Entry<'a, T> {
+    /// the point of the cell
+    pub fn point(&self) -> Point {
+        self.point
+    }
+
+    /// stores value if the cell is vacant and returns the value of the cell
+    pub fn or_insert(self, value: T) -> &'a mut T {
+        self.or_insert_with(|| value)
+    }
+
+    /// stores the result of f if the cell is vacant and returns the value of the cell
+    pub fn or_insert_with<F: FnOnce() -> T>(self, f: F) -> &'a mut T {
+        match self.slot {
+            Slot::Sparse(entry) => entry.or_insert_with(f),
+            Slot::Dense(cell) => cell.get_or_insert_with(f),
+        }
+    }
+
+    /// calls f with the value of the cell if it's occupied
+    pub fn and_modify<F: FnOnce(&mut T)>(self, f: F) -> Entry<'a, T> {
+        match self.slot {
+            Slot::Sparse(entry) => Entry { point: self.point, slot: Slot::Sparse(entry.and_modify(f)) },
+            Slot::Dense(cell) => {
+                if let Some(value) = cell.as_mut() {
+                    f(value);
+                }
+                Entry { point: self.point, slot: Slot::Dense(cell) }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Count;
+
+    #[test]
+    fn sparse_and_dense_entries_agree() {
+        let mut sparse = SparseGrid::new(0);
+        let mut dense = Grid::new(3, 3, None);
+        let points = [Point::new(2, 1), Point::ZERO, Point::new(2, 1), Point::new(2, 1)];
+        for point in points {
+            let a = sparse.entry(point).and_modify(|n| *n *= 2).or_insert_with(|| 1);
+            let b = dense.entry(point).unwrap().and_modify(|n| *n *= 2).or_insert_with(|| 1);
+            assert_eq!(a, b);
+        }
+        assert_eq!(sparse[Point::new(2, 1)], 4);
+        assert_eq!(dense.count(Option::is_some), sparse.len());
+        assert_eq!(dense.entry(Point::new(1, 2)).unwrap().point(), Point::new(1, 2));
+    }
+}
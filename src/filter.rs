@@ -0,0 +1,78 @@
+use num_traits::AsPrimitive;
+use crate::{Grid, Point};
+
+/// what a kernel reads when it reaches past the edge of the grid, see [`Grid::convolve`]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum BorderPolicy {
+    /// the nearest cell on the edge
+    Clamp,
+    /// the cell on the opposite side, as if the grid was tiled
+    Wrap,
+    /// a fixed value
+    Constant(isize),
+}
+
+impl<T: AsPrimitive<isize>> Grid<T> {
+    /// a new grid where every cell is the weighted sum of the cells around it, using the kernel
+    /// as the weights with its center on the cell. the center of a kernel with an even size is the
+    /// cell right and down of the middle. the kernel isn't flipped, so strictly speaking this is a
+    /// correlation, which is what you want for the usual symmetric kernels
+    /// ```
+    /// use point_index::*;
+    /// let life: Grid<bool> = Grid::parse_with("
+    /// .#.
+    /// .#.
+    /// .#.
+    /// ", |c| Some(c == '#')).unwrap();
+    /// let mut kernel = Grid::new(3, 3, 1);
+    /// kernel[Point::ONE] = 0;
+    /// let neighbors = life.convolve(&kernel, BorderPolicy::Constant(0));
+    /// assert_eq!(neighbors.as_slice(), &[2, 1, 2, 3, 2, 3, 2, 1, 2]);
+    /// let wrapped = life.convolve(&kernel, BorderPolicy::Wrap);
+    /// assert_eq!(wrapped.row(0), &[3, 2, 3]);
+    /// ```
+    pub fn convolve(&self, kernel: &Grid<isize>, border: BorderPolicy) -> Grid<isize> {
+        let center = Point::new(kernel.width / 2, kernel.height / 2);
+        let weights: Vec<(Point, isize)> = kernel
+            .enumerate()
+            .filter(|&(_, &weight)| weight != 0)
+            .map(|(offset, &weight)| (offset - center, weight))
+            .collect();
+        Grid::from_fn(self.width, self.height, |point| {
+            weights.iter().map(|&(offset, weight)| weight * self.read(point + offset, border)).sum()
+        })
+    }
+
+    /// the value of a cell as an isize, following the border policy outside the grid
+    fn read(&self, point: Point, border: BorderPolicy) -> isize {
+        if let Some(i) = self.flat_index(point) {
+            return self.data[i].as_();
+        }
+        let point = match border {
+            BorderPolicy::Clamp => point.clamp_to_bounds(self.width, self.height),
+            BorderPolicy::Wrap => point.wrap(self.width, self.height),
+            BorderPolicy::Constant(value) => return value,
+        };
+        self[point].as_()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn border_policies() {
+        let grid = Grid::from_fn(4, 3, |p| p.x + 10 * p.y);
+        let identity = Grid::from_vec(1, 1, vec![1]).unwrap();
+        assert_eq!(grid.convolve(&identity, BorderPolicy::Constant(7)), grid);
+        let shift_left = Grid::from_vec(3, 1, vec![0, 0, 1]).unwrap();
+        let clamped = grid.convolve(&shift_left, BorderPolicy::Clamp);
+        assert_eq!(clamped.column(3).copied().collect::<Vec<_>>(), [3, 13, 23]);
+        let wrapped = grid.convolve(&shift_left, BorderPolicy::Wrap);
+        assert_eq!(wrapped.column(3).copied().collect::<Vec<_>>(), [0, 10, 20]);
+        assert_eq!(grid.convolve(&shift_left, BorderPolicy::Constant(-1))[Point::new(3, 2)], -1);
+        let empty: Grid<u8> = Grid::new(0, 0, 0);
+        assert_eq!(empty.convolve(&identity, BorderPolicy::Wrap).dims(), Point::ZERO);
+    }
+}
@@ -0,0 +1,1377 @@
+use std::error::Error;
+use std::fmt;
+use std::mem;
+use std::ops::{Index, IndexMut, Range};
+use std::str::FromStr;
+use num_traits::PrimInt;
+use crate::{Count, Direction, GenericPoint, Get, InsertRemove, Point, Rect, Set};
+
+/// a 2d grid stored in a single row major vec, which keeps it cache friendly and makes it
+/// impossible to end up with ragged rows like a `Vec<Vec<T>>` can
+/// ```
+/// use point_index::*;
+/// let mut grid = Grid::new(3, 2, 0);
+/// grid[Point::new(2, 1)] = 5;
+/// assert_eq!(grid.get_option(Point::new(2, 1)), Some(&5));
+/// assert_eq!(grid.get_option(Point::new(3, 1)), None);
+/// assert_eq!(grid.as_slice(), &[0, 0, 0, 0, 0, 5]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Grid<T> {
+    pub(crate) data: Vec<T>,
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    /// creates a width by height grid with every cell set to fill
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid { data: vec![fill; width * height], width, height }
+    }
+}
+
+impl<T> Grid<T> {
+    /// creates a grid from its cells in row major order, returns None if the number of cells
+    /// doesn't match the dimensions
+    /// ```
+    /// use point_index::*;
+    /// let grid = Grid::from_vec(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// assert_eq!(grid[Point::new(0, 1)], 3);
+    /// assert!(Grid::from_vec(2, 2, vec![1, 2, 3]).is_none());
+    /// ```
+    pub fn from_vec(width: usize, height: usize, data: Vec<T>) -> Option<Grid<T>> {
+        if width.checked_mul(height)? != data.len() {
+            return None;
+        }
+        Some(Grid { data, width, height })
+    }
+
+    /// creates a width by height grid by calling f with every point, in reading order
+    /// ```
+    /// use point_index::*;
+    /// let checkerboard = Grid::from_fn(3, 2, |p| (p.x + p.y) % 2 == 0);
+    /// assert_eq!(checkerboard.as_slice(), &[true, false, true, false, true, false]);
+    /// let distance = Grid::from_fn(5, 5, |p| p.manhattan_distance(Point::new(2, 2)));
+    /// assert_eq!(distance[Point::new(0, 4)], 4);
+    /// ```
+    pub fn from_fn<F: FnMut(Point) -> T>(width: usize, height: usize, mut f: F) -> Grid<T> {
+        let data = (0..width * height).map(|i| f(Point::from_index(i, width))).collect();
+        Grid { data, width, height }
+    }
+
+    /// parses a block of text into a grid, one row per line, mapping every character through f.
+    /// empty lines at the start and end are ignored so raw string literals work as expected.
+    /// fails if f returns None or the rows have different lengths
+    /// ```
+    /// use point_index::*;
+    /// let heights = Grid::parse_with("123\n456", |c| c.to_digit(10)).unwrap();
+    /// assert_eq!(heights[Point::new(2, 1)], 6);
+    /// assert!(Grid::parse_with("12\n3x", |c| c.to_digit(10)).is_err());
+    /// ```
+    pub fn parse_with<F: FnMut(char) -> Option<T>>(s: &str, mut f: F) -> Result<Grid<T>, ParseGridError> {
+        let lines: Vec<&str> = s.lines().collect();
+        let start = lines.iter().position(|line| !line.is_empty()).unwrap_or(lines.len());
+        let end = lines.iter().rposition(|line| !line.is_empty()).map_or(start, |i| i + 1);
+        let lines = &lines[start..end];
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut data = Vec::with_capacity(width * lines.len());
+        for line in lines {
+            let before = data.len();
+            for c in line.chars() {
+                data.push(f(c).ok_or(ParseGridError(()))?);
+            }
+            if data.len() - before != width {
+                return Err(ParseGridError(()));
+            }
+        }
+        Ok(Grid { data, width, height: lines.len() })
+    }
+
+    /// the number of columns
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// the number of rows
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// the width and height of the grid as a point, which is also one past its bottom right cell
+    /// ```
+    /// use point_index::*;
+    /// let grid = Grid::new(4, 3, '.');
+    /// assert_eq!(grid.dims(), Point::new(4, 3));
+    /// assert_eq!(grid.dims(), Point::new(grid.width(), grid.height()));
+    /// ```
+    pub fn dims(&self) -> Point {
+        Point::new(self.width, self.height)
+    }
+
+    /// whether the point is one of the cells of the grid
+    /// ```
+    /// use point_index::*;
+    /// let grid = Grid::new(4, 3, '.');
+    /// assert!(grid.in_bounds(Point::new(3, 2)));
+    /// assert!(!grid.in_bounds(Point::new(4, 2)));
+    /// assert!(!grid.in_bounds(Point::new_isize(-1, 0)));
+    /// ```
+    pub fn in_bounds<P: PrimInt>(&self, point: GenericPoint<P>) -> bool {
+        self.flat_index(point).is_some()
+    }
+
+    /// iterates over the rows from top to bottom, each row is a slice. a grid with a width of 0
+    /// has no cells to slice, so it yields no rows at all
+    /// ```
+    /// use point_index::*;
+    /// let grid = Grid::from_vec(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// let sums: Vec<i32> = grid.rows().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(sums, [3, 7]);
+    /// ```
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.data.chunks_exact(self.width.max(1))
+    }
+
+    /// iterates mutably over the rows from top to bottom, each row is a slice. like rows this
+    /// yields nothing for a grid with a width of 0
+    pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> + ExactSizeIterator {
+        self.data.chunks_exact_mut(self.width.max(1))
+    }
+
+    /// iterates over the columns from left to right, each column is an iterator that goes from top
+    /// to bottom by stepping through the flat storage, so nothing is allocated
+    /// ```
+    /// use point_index::*;
+    /// let grid = Grid::from_vec(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// let sums: Vec<i32> = grid.columns().map(|column| column.sum()).collect();
+    /// assert_eq!(sums, [4, 6]);
+    /// ```
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.data.get(x..).unwrap_or(&[]).iter().step_by(self.width))
+    }
+
+    /// iterates mutably over the columns from left to right, each column goes from top to bottom.
+    /// like columns this steps through the flat storage, so nothing is allocated
+    /// ```
+    /// use point_index::*;
+    /// let mut grid = Grid::new(3, 2, 0);
+    /// for (x, column) in grid.columns_mut().enumerate() {
+    ///     column.for_each(|cell| *cell = x);
+    /// }
+    /// assert_eq!(grid.as_slice(), &[0, 1, 2, 0, 1, 2]);
+    /// ```
+    pub fn columns_mut(&mut self) -> impl Iterator<Item = impl Iterator<Item = &mut T>> {
+        let (width, height) = (self.width, self.height);
+        let data = self.data.as_mut_ptr();
+        (0..width).map(move |x| {
+            (0..height).map(move |y| {
+                // SAFETY: y * width + x is inside data, every index is handed out by exactly one
+                // column and at most once since the iterators can't be cloned, so no two
+                // references alias, and none of them outlive the borrow of self
+                unsafe { &mut *data.add(y * width + x) }
+            })
+        })
+    }
+
+    /// the row at y as a slice, panics if y is outside the grid
+    /// ```
+    /// use point_index::*;
+    /// let grid = Grid::from_vec(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// assert_eq!(grid.row(1), &[3, 4]);
+    /// ```
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row is outside the grid");
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// the row at y as a mutable slice, panics if y is outside the grid
+    /// ```
+    /// use point_index::*;
+    /// let mut grid = Grid::new(3, 2, 0);
+    /// grid.row_mut(0).copy_from_slice(&[1, 2, 3]);
+    /// assert_eq!(grid.as_slice(), &[1, 2, 3, 0, 0, 0]);
+    /// ```
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(y < self.height, "row is outside the grid");
+        &mut self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// iterates over the column at x from top to bottom, panics if x is outside the grid
+    /// ```
+    /// use point_index::*;
+    /// let grid = Grid::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 4, 6]);
+    /// assert_eq!(grid.column(0).rev().next(), Some(&5));
+    /// ```
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column is outside the grid");
+        self.data.get(x..).unwrap_or(&[]).iter().step_by(self.width)
+    }
+
+    /// iterates mutably over the column at x from top to bottom, panics if x is outside the grid
+    /// ```
+    /// use point_index::*;
+    /// // tilt the rocks in one column north
+    /// let mut platform: Grid<char> = "..\nO.\n.O".parse().unwrap();
+    /// let rocks = platform.column(1).filter(|&&c| c == 'O').count();
+    /// for (i, cell) in platform.column_mut(1).enumerate() {
+    ///     *cell = if i < rocks { 'O' } else { '.' };
+    /// }
+    /// assert_eq!(platform, ".O\nO.\n..".parse().unwrap());
+    /// ```
+    pub fn column_mut(&mut self, x: usize) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator {
+        assert!(x < self.width, "column is outside the grid");
+        self.data.get_mut(x..).unwrap_or(&mut []).iter_mut().step_by(self.width)
+    }
+
+    /// a new grid with the rows and columns swapped, the cell at (x, y) ends up at (y, x).
+    /// this takes O(width * height) time and allocates a new grid
+    /// ```
+    /// use point_index::*;
+    /// let grid = Grid::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let transposed = grid.transpose();
+    /// assert_eq!(transposed.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    /// assert_eq!(transposed[Point::new(1, 2)], grid[Point::new(2, 1)]);
+    /// ```
+    pub fn transpose(&self) -> Grid<T> where T: Clone {
+        Grid::from_fn(self.height, self.width, |p| self[p.swap_xy()].clone())
+    }
+
+    /// a new grid rotated a quarter turn clockwise, the width and height are swapped
+    /// ```
+    /// use point_index::*;
+    /// let grid: Grid<char> = "ab\ncd\nef".parse().unwrap();
+    /// assert_eq!(grid.rotate_cw(), "eca\nfdb".parse().unwrap());
+    /// ```
+    pub fn rotate_cw(&self) -> Grid<T> where T: Clone {
+        let last_y = self.height as isize - 1;
+        Grid::from_fn(self.height, self.width, |p| self[Point::new_isize(p.y, last_y - p.x)].clone())
+    }
+
+    /// a new grid rotated a quarter turn counter clockwise, the width and height are swapped
+    /// ```
+    /// use point_index::*;
+    /// let grid: Grid<char> = "ab\ncd\nef".parse().unwrap();
+    /// assert_eq!(grid.rotate_ccw(), "bdf\nace".parse().unwrap());
+    /// ```
+    pub fn rotate_ccw(&self) -> Grid<T> where T: Clone {
+        let last_x = self.width as isize - 1;
+        Grid::from_fn(self.height, self.width, |p| self[Point::new_isize(last_x - p.y, p.x)].clone())
+    }
+
+    /// a new grid rotated half a turn
+    /// ```
+    /// use point_index::*;
+    /// let grid: Grid<char> = "ab\ncd\nef".parse().unwrap();
+    /// assert_eq!(grid.rotate_180(), "fe\ndc\nba".parse().unwrap());
+    /// ```
+    pub fn rotate_180(&self) -> Grid<T> where T: Clone {
+        let mut data = self.data.clone();
+        data.reverse();
+        Grid { data, width: self.width, height: self.height }
+    }
+
+    /// a new grid mirrored left to right, each row is reversed
+    /// ```
+    /// use point_index::*;
+    /// let grid: Grid<char> = "ab\ncd".parse().unwrap();
+    /// assert_eq!(grid.flip_horizontal(), "ba\ndc".parse().unwrap());
+    /// ```
+    pub fn flip_horizontal(&self) -> Grid<T> where T: Clone {
+        let mut flipped = self.clone();
+        for row in flipped.rows_mut() {
+            row.reverse();
+        }
+        flipped
+    }
+
+    /// a new grid mirrored top to bottom, the order of the rows is reversed
+    /// ```
+    /// use point_index::*;
+    /// let grid: Grid<char> = "ab\ncd".parse().unwrap();
+    /// assert_eq!(grid.flip_vertical(), "cd\nab".parse().unwrap());
+    /// ```
+    pub fn flip_vertical(&self) -> Grid<T> where T: Clone {
+        let data = self.rows().rev().flatten().cloned().collect();
+        Grid { data, width: self.width, height: self.height }
+    }
+
+    /// a new grid holding a copy of the cells inside rect, returns None if rect doesn't fit
+    /// inside the grid
+    /// ```
+    /// use point_index::*;
+    /// let grid = Grid::from_fn(4, 4, |p| p.to_index(4).unwrap());
+    /// let corner = grid.crop(Rect::new(Point::new(2, 2), 2, 2)).unwrap();
+    /// assert_eq!(corner.as_slice(), &[10, 11, 14, 15]);
+    /// assert!(grid.crop(Rect::new(Point::new(3, 3), 2, 2)).is_none());
+    /// ```
+    pub fn crop(&self, rect: Rect) -> Option<Grid<T>> where T: Clone {
+        if !self.fits(rect) {
+            return None;
+        }
+        Some(Grid::from_fn(rect.width, rect.height, |p| self[p + rect.origin].clone()))
+    }
+
+    /// a new grid with a border n cells wide filled with fill around the original cells, handy as
+    /// a sentinel so neighbor loops don't need bounds checks
+    /// ```
+    /// use point_index::*;
+    /// let grid: Grid<char> = "ab".parse().unwrap();
+    /// assert_eq!(grid.pad(1, '#'), "####\n#ab#\n####".parse().unwrap());
+    /// ```
+    pub fn pad(&self, n: usize, fill: T) -> Grid<T> where T: Clone {
+        let offset = Point::ONE * n as isize;
+        Grid::from_fn(self.width + 2 * n, self.height + 2 * n, |p| {
+            self.get_option(p - offset).unwrap_or(&fill).clone()
+        })
+    }
+
+    /// a new grid n times as wide and as tall where every cell became an n by n block of copies
+    /// ```
+    /// use point_index::*;
+    /// let grid: Grid<char> = "ab\ncd".parse().unwrap();
+    /// assert_eq!(grid.scale_up(2), "aabb\naabb\nccdd\nccdd".parse().unwrap());
+    /// ```
+    pub fn scale_up(&self, n: usize) -> Grid<T> where T: Clone {
+        Grid::from_fn(self.width * n, self.height * n, |p| self[p / n as isize].clone())
+    }
+
+    /// a new grid where every cell was replaced by the block f makes for it, the blocks are laid
+    /// out in the same order as the cells. returns None if the blocks don't all have the same size
+    /// ```
+    /// use point_index::*;
+    /// let pipes: Grid<char> = "|-".parse().unwrap();
+    /// let big = pipes.scale_up_with(|&c| match c {
+    ///     '|' => ".#.\n.#.\n.#.".parse().unwrap(),
+    ///     _ => "...\n###\n...".parse().unwrap(),
+    /// });
+    /// assert_eq!(big.unwrap(), ".#....\n.#.###\n.#....".parse().unwrap());
+    /// let uneven = pipes.scale_up_with(|&c| Grid::new(if c == '|' { 1 } else { 2 }, 1, c));
+    /// assert!(uneven.is_none());
+    /// ```
+    pub fn scale_up_with<U, F: FnMut(&T) -> Grid<U>>(&self, f: F) -> Option<Grid<U>> {
+        let blocks: Vec<Grid<U>> = self.data.iter().map(f).collect();
+        let Some(first) = blocks.first() else {
+            return Some(Grid { data: Vec::new(), width: 0, height: 0 });
+        };
+        let (block_width, block_height) = (first.width, first.height);
+        if blocks.iter().any(|block| block.width != block_width || block.height != block_height) {
+            return None;
+        }
+        let width = self.width * block_width;
+        let height = self.height * block_height;
+        let mut cells: Vec<_> = blocks.into_iter().map(|block| block.data.into_iter()).collect();
+        let mut data = Vec::with_capacity(width * height);
+        for row in cells.chunks_mut(self.width.max(1)) {
+            for _ in 0..block_height {
+                for block in row.iter_mut() {
+                    data.extend(block.by_ref().take(block_width));
+                }
+            }
+        }
+        Some(Grid { data, width, height })
+    }
+
+    /// a new grid made of nx by ny copies of the grid side by side
+    /// ```
+    /// use point_index::*;
+    /// let grid: Grid<char> = "ab".parse().unwrap();
+    /// assert_eq!(grid.tile(2, 2), "abab\nabab".parse().unwrap());
+    /// ```
+    pub fn tile(&self, nx: usize, ny: usize) -> Grid<T> where T: Clone {
+        self.tile_with(nx, ny, |_, cell| cell.clone())
+    }
+
+    /// a new grid made of nx by ny copies of the grid side by side, where every cell is f called
+    /// with the position of its copy, counted in whole tiles, and the original cell
+    /// ```
+    /// use point_index::*;
+    /// let risk = Grid::parse_with("89", |c| c.to_digit(10)).unwrap();
+    /// let cave = risk.tile_with(3, 1, |tile, &r| (r + tile.x as u32 - 1) % 9 + 1);
+    /// assert_eq!(cave.as_slice(), &[8, 9, 9, 1, 1, 2]);
+    /// ```
+    pub fn tile_with<U, F: FnMut(Point, &T) -> U>(&self, nx: usize, ny: usize, mut f: F) -> Grid<U> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        Grid::from_fn(self.width * nx, self.height * ny, |p| {
+            let tile = Point::new_isize(p.x / width, p.y / height);
+            f(tile, &self[Point::new_isize(p.x % width, p.y % height)])
+        })
+    }
+
+    /// changes the size of the grid in place, cells keep their points, cells that no longer fit
+    /// are dropped and new cells are set to fill
+    /// ```
+    /// use point_index::*;
+    /// let mut grid: Grid<char> = "ab\ncd".parse().unwrap();
+    /// grid.resize(3, 1, '.');
+    /// assert_eq!(grid, "ab.".parse().unwrap());
+    /// ```
+    pub fn resize(&mut self, width: usize, height: usize, fill: T) where T: Clone {
+        *self = Grid::from_fn(width, height, |p| self.get_option(p).unwrap_or(&fill).clone());
+    }
+
+    /// a new grid of the same size made by calling f on every cell
+    /// ```
+    /// use point_index::*;
+    /// let heights = Grid::parse_with("19\n28", |c| c.to_digit(10)).unwrap();
+    /// let risk = heights.map(|&h| h + 1);
+    /// assert_eq!(risk.as_slice(), &[2, 10, 3, 9]);
+    /// ```
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { data: self.data.iter().map(f).collect(), width: self.width, height: self.height }
+    }
+
+    /// a new grid of the same size made by calling f on every cell and its point
+    /// ```
+    /// use point_index::*;
+    /// let grid: Grid<char> = "a.\n.b".parse().unwrap();
+    /// let labels = grid.map_with_point(|p, &c| if c == '.' { None } else { Some((c, p)) });
+    /// assert_eq!(labels[Point::new(1, 1)], Some(('b', Point::new(1, 1))));
+    /// ```
+    pub fn map_with_point<U, F: FnMut(Point, &T) -> U>(&self, mut f: F) -> Grid<U> {
+        Grid::from_fn(self.width, self.height, |p| f(p, &self[p]))
+    }
+
+    /// the next generation of a cellular automaton, a new grid made by calling f with every point
+    /// and the whole current grid. f only ever sees the old cells, so the order cells are updated
+    /// in doesn't matter
+    /// ```
+    /// use point_index::*;
+    /// let blinker: Grid<bool> = Grid::parse_with("
+    /// .....
+    /// ..#..
+    /// ..#..
+    /// ..#..
+    /// .....
+    /// ", |c| Some(c == '#')).unwrap();
+    /// let life = |p: Point, g: &Grid<bool>| {
+    ///     let alive = g.neighbors8(p).filter(|(_, &c)| c).count();
+    ///     alive == 3 || (g[p] && alive == 2)
+    /// };
+    /// let next = blinker.step(life);
+    /// let alive: Vec<Point> = next.find_all(|&c| c).collect();
+    /// assert_eq!(alive, [Point::new(1, 2), Point::new(2, 2), Point::new(3, 2)]);
+    /// assert_eq!(next.step(life), blinker);
+    /// ```
+    pub fn step<F: FnMut(Point, &Grid<T>) -> T>(&self, mut f: F) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |p| f(p, self))
+    }
+
+    /// like [`Grid::step`] but writes the next generation into scratch and then swaps the two,
+    /// so self holds the new cells and scratch the old ones. scratch is resized to match if needed,
+    /// which lets a simulation reuse the same two allocations for every generation
+    /// ```
+    /// use point_index::*;
+    /// let mut counter = Grid::new(3, 2, 0);
+    /// let mut scratch = Grid::new(0, 0, 0);
+    /// for _ in 0..5 {
+    ///     counter.step_into(&mut scratch, |p, g| g[p] + p.x);
+    /// }
+    /// assert_eq!(counter.row(1), &[0, 5, 10]);
+    /// assert_eq!(scratch.row(1), &[0, 4, 8]);
+    /// ```
+    pub fn step_into<F: FnMut(Point, &Grid<T>) -> T>(&mut self, scratch: &mut Grid<T>, mut f: F) {
+        scratch.data.clear();
+        scratch.data.extend((0..self.data.len()).map(|i| f(Point::from_index(i, self.width), self)));
+        scratch.width = self.width;
+        scratch.height = self.height;
+        mem::swap(self, scratch);
+    }
+
+    /// a new grid made by calling f on the matching cells of both grids, returns None if the
+    /// grids don't have the same size
+    /// ```
+    /// use point_index::*;
+    /// let terrain = Grid::from_vec(2, 1, vec![3, 5]).unwrap();
+    /// let mask = Grid::from_vec(2, 1, vec![true, false]).unwrap();
+    /// let cost = terrain.zip(&mask, |&t, &m| if m { t } else { 0 }).unwrap();
+    /// assert_eq!(cost.as_slice(), &[3, 0]);
+    /// assert!(terrain.zip(&Grid::new(1, 2, true), |&t, _| t).is_none());
+    /// ```
+    pub fn zip<U, V, F: FnMut(&T, &U) -> V>(&self, other: &Grid<U>, mut f: F) -> Option<Grid<V>> {
+        if self.width != other.width || self.height != other.height {
+            return None;
+        }
+        let data = self.data.iter().zip(&other.data).map(|(a, b)| f(a, b)).collect();
+        Some(Grid { data, width: self.width, height: self.height })
+    }
+
+    /// iterates over the cells that differ between two grids of the same size, in reading order,
+    /// with the value in self and the value in other. returns None if the sizes differ
+    /// ```
+    /// use point_index::*;
+    /// let before: Grid<char> = "#..\n.#.".parse().unwrap();
+    /// let after: Grid<char> = "#.#\n...".parse().unwrap();
+    /// let changes: Vec<(Point, &char, &char)> = before.diff(&after).unwrap().collect();
+    /// assert_eq!(changes, [(Point::new(2, 0), &'.', &'#'), (Point::new(1, 1), &'#', &'.')]);
+    /// ```
+    pub fn diff<'a>(&'a self, other: &'a Grid<T>) -> Option<impl Iterator<Item = (Point, &'a T, &'a T)>>
+    where
+        T: PartialEq,
+    {
+        if self.width != other.width || self.height != other.height {
+            return None;
+        }
+        let changes = self.enumerate().zip(&other.data).filter(|((_, a), b)| a != b);
+        Some(changes.map(|((point, a), b)| (point, a, b)))
+    }
+
+    /// iterates over every cell and its point in reading order
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width;
+        self.data.iter().enumerate().map(move |(i, cell)| (Point::from_index(i, width), cell))
+    }
+
+    /// the point of the first cell in reading order equal to value
+    /// ```
+    /// use point_index::*;
+    /// let maze: Grid<char> = "#.S\n..E".parse().unwrap();
+    /// assert_eq!(maze.find(&'S'), Some(Point::new(2, 0)));
+    /// assert_eq!(maze.find(&'X'), None);
+    /// ```
+    pub fn find(&self, value: &T) -> Option<Point> where T: PartialEq {
+        self.position(|cell| cell == value)
+    }
+
+    /// the point of the first cell in reading order that matches the predicate
+    /// ```
+    /// use point_index::*;
+    /// let maze: Grid<char> = "#.S\n..E".parse().unwrap();
+    /// assert_eq!(maze.position(|c| c.is_uppercase()), Some(Point::new(2, 0)));
+    /// ```
+    pub fn position<F: FnMut(&T) -> bool>(&self, predicate: F) -> Option<Point> {
+        self.data.iter().position(predicate).map(|i| Point::from_index(i, self.width))
+    }
+
+    /// iterates over the points of every cell that matches the predicate, in reading order
+    /// ```
+    /// use point_index::*;
+    /// let maze: Grid<char> = "#.S\n..E".parse().unwrap();
+    /// let open: Vec<Point> = maze.find_all(|&c| c == '.').collect();
+    /// assert_eq!(open, [Point::new(1, 0), Point::new(0, 1), Point::new(1, 1)]);
+    /// ```
+    pub fn find_all<'a, F: FnMut(&T) -> bool + 'a>(&'a self, mut predicate: F) -> impl Iterator<Item = Point> + 'a {
+        self.enumerate().filter_map(move |(point, cell)| predicate(cell).then_some(point))
+    }
+
+    /// iterates over the orthogonal neighbors of point that are inside the grid, with their values,
+    /// in the same order as [`Point::neighbors4`]
+    /// ```
+    /// use point_index::*;
+    /// let grid: Grid<char> = "ab\ncd".parse().unwrap();
+    /// let around: Vec<(Point, &char)> = grid.neighbors4(Point::ZERO).collect();
+    /// assert_eq!(around, [(Point::new(0, 1), &'c'), (Point::new(1, 0), &'b')]);
+    /// ```
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point.neighbors4().into_iter().filter_map(move |p| Some((p, self.get_option(p)?)))
+    }
+
+    /// iterates over the surrounding cells of point that are inside the grid, with their values,
+    /// in reading order
+    /// ```
+    /// use point_index::*;
+    /// let mines: Grid<char> = "*..\n.*.\n...".parse().unwrap();
+    /// assert_eq!(mines.neighbors8(Point::new(2, 2)).filter(|&(_, &c)| c == '*').count(), 1);
+    /// assert_eq!(mines.neighbors8(Point::new(0, 1)).count(), 5);
+    /// ```
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point.neighbors8().into_iter().filter_map(move |p| Some((p, self.get_option(p)?)))
+    }
+
+    /// swaps the values of two cells, returns None and leaves the grid unchanged if either point is
+    /// outside the grid
+    /// ```
+    /// use point_index::*;
+    /// let mut puzzle = Grid::from_vec(2, 2, vec![1, 2, 3, 0]).unwrap();
+    /// assert_eq!(puzzle.swap(Point::new(1, 1), Point::new(1, 0)), Some(()));
+    /// assert_eq!(puzzle.as_slice(), &[1, 0, 3, 2]);
+    /// assert_eq!(puzzle.swap(Point::ZERO, Point::new(2, 0)), None);
+    /// ```
+    pub fn swap(&mut self, a: Point, b: Point) -> Option<()> {
+        let (a, b) = (self.flat_index(a)?, self.flat_index(b)?);
+        self.data.swap(a, b);
+        Some(())
+    }
+
+    /// mutable references to several cells at once, returns None if any point is outside the grid
+    /// or the same cell is asked for twice
+    /// ```
+    /// use point_index::*;
+    /// let mut stacks = Grid::from_vec(3, 1, vec![vec![1, 2], vec![], vec![3]]).unwrap();
+    /// let [from, to] = stacks.get_many_mut([Point::new(0, 0), Point::new(2, 0)]).unwrap();
+    /// to.append(from);
+    /// assert_eq!(stacks.as_slice(), &[vec![], vec![], vec![3, 1, 2]]);
+    /// assert!(stacks.get_many_mut([Point::ZERO, Point::ZERO]).is_none());
+    /// ```
+    pub fn get_many_mut<const N: usize>(&mut self, points: [Point; N]) -> Option<[&mut T; N]> {
+        let mut indices = [0; N];
+        for (index, point) in indices.iter_mut().zip(points) {
+            *index = self.flat_index(point)?;
+        }
+        // hand out the cells in memory order so each one can be split off the rest of the storage
+        let mut order: [usize; N] = std::array::from_fn(|i| i);
+        order.sort_unstable_by_key(|&i| indices[i]);
+        if order.windows(2).any(|pair| indices[pair[0]] == indices[pair[1]]) {
+            return None;
+        }
+        let mut cells: [Option<&mut T>; N] = std::array::from_fn(|_| None);
+        let (mut rest, mut start) = (self.data.as_mut_slice(), 0);
+        for i in order {
+            let (cell, tail) = mem::take(&mut rest)[indices[i] - start..].split_first_mut()?;
+            cells[i] = Some(cell);
+            (rest, start) = (tail, indices[i] + 1);
+        }
+        Some(cells.map(|cell| cell.unwrap()))
+    }
+
+    /// sets every cell of rect to value, returns None and leaves the grid unchanged if rect doesn't
+    /// fit inside the grid
+    /// ```
+    /// use point_index::*;
+    /// let mut lights = Grid::new(4, 3, false);
+    /// lights.fill_rect(Rect::new(Point::new(1, 1), 3, 2), true).unwrap();
+    /// assert_eq!(lights.count_eq(&true), 6);
+    /// assert!(lights.fill_rect(Rect::new(Point::new(2, 2), 3, 1), true).is_none());
+    /// ```
+    pub fn fill_rect(&mut self, rect: Rect, value: T) -> Option<()> where T: Clone {
+        self.view_mut(rect)?.fill(value);
+        Some(())
+    }
+
+    /// sets every cell that matches the predicate to value
+    /// ```
+    /// use point_index::*;
+    /// let mut map: Grid<char> = "#.\n.#".parse().unwrap();
+    /// map.fill_where(|&c| c == '.', ' ');
+    /// assert_eq!(map.as_slice(), &['#', ' ', ' ', '#']);
+    /// ```
+    pub fn fill_where<F: FnMut(&T) -> bool>(&mut self, mut predicate: F, value: T) where T: Clone {
+        for cell in self.data.iter_mut().filter(|cell| predicate(cell)) {
+            *cell = value.clone();
+        }
+    }
+
+    /// copies other onto the grid with its top left corner at offset, the parts of other that
+    /// land outside the grid are cut off
+    /// ```
+    /// use point_index::*;
+    /// let sprite: Grid<char> = "ab\ncd".parse().unwrap();
+    /// let mut screen = Grid::new(3, 3, '.');
+    /// screen.blit(&sprite, Point::new_isize(2, -1));
+    /// assert_eq!(screen, "..c\n...\n...".parse().unwrap());
+    /// ```
+    pub fn blit(&mut self, other: &Grid<T>, offset: Point) where T: Clone {
+        let Some((xs, ys)) = self.overlap(other, offset) else {
+            return;
+        };
+        let dx = (xs.start as isize + offset.x) as usize;
+        for y in ys {
+            let row = &mut self.row_mut((y as isize + offset.y) as usize)[dx..dx + xs.len()];
+            row.clone_from_slice(&other.row(y)[xs.clone()]);
+        }
+    }
+
+    /// like [`Grid::blit`] but leaves the cells under the parts of other that are equal to
+    /// transparent alone
+    /// ```
+    /// use point_index::*;
+    /// let sprite: Grid<char> = " o \nooo".parse().unwrap();
+    /// let mut screen = Grid::new(4, 2, '.');
+    /// screen.blit_masked(&sprite, Point::new(1, 0), &' ');
+    /// assert_eq!(screen, "..o.\n.ooo".parse().unwrap());
+    /// ```
+    pub fn blit_masked(&mut self, other: &Grid<T>, offset: Point, transparent: &T) where T: Clone + PartialEq {
+        let Some((xs, ys)) = self.overlap(other, offset) else {
+            return;
+        };
+        for y in ys {
+            for x in xs.clone() {
+                let source = &other[Point::new(x, y)];
+                if source != transparent {
+                    self[Point::new(x, y) + offset] = source.clone();
+                }
+            }
+        }
+    }
+
+    /// moves every cell n steps in direction, cells that move off the grid are dropped and the
+    /// cells left behind are set to fill. direction can be diagonal
+    /// ```
+    /// use point_index::*;
+    /// let mut screen: Grid<char> = "ab.\ncd.\n...".parse().unwrap();
+    /// screen.shift(DOWN_RIGHT, 1, ' ');
+    /// assert_eq!(screen, "   \n ab\n cd".parse().unwrap());
+    /// screen.shift(LEFT, 5, '.');
+    /// assert_eq!(screen.count_eq(&'.'), 9);
+    /// ```
+    pub fn shift(&mut self, direction: Point, n: usize, fill: T) where T: Clone {
+        // clamp before anything gets multiplied, shifting by the whole grid already clears it
+        let dx = direction.x.unsigned_abs().saturating_mul(n).min(self.width);
+        let dy = direction.y.unsigned_abs().saturating_mul(n).min(self.height);
+        if dx > 0 {
+            for row in self.data.chunks_exact_mut(self.width) {
+                if direction.x > 0 {
+                    row.rotate_right(dx);
+                    row[..dx].fill(fill.clone());
+                } else {
+                    row.rotate_left(dx);
+                    row[self.width - dx..].fill(fill.clone());
+                }
+            }
+        }
+        let cells = dy * self.width;
+        if direction.y > 0 {
+            self.data.rotate_right(cells);
+            self.data[..cells].fill(fill);
+        } else if direction.y < 0 {
+            self.data.rotate_left(cells);
+            let len = self.data.len();
+            self.data[len - cells..].fill(fill);
+        }
+    }
+
+    /// slides every movable cell as far as it can go in direction, like rocks rolling on a tilted
+    /// platform. movable cells stop at blockers, the edge of the grid or other movable cells that
+    /// already stopped. cells that are neither movable nor blockers count as empty, and movable
+    /// cells swap places with them as they pass. a cell that matches both predicates is a blocker.
+    /// returns how many cells moved
+    /// ```
+    /// use point_index::*;
+    /// let mut platform: Grid<char> = "
+    /// .O.
+    /// #.O
+    /// O.O
+    /// ".parse().unwrap();
+    /// let moved = platform.tilt(Direction::Up, |&c| c == 'O', |&c| c == '#');
+    /// assert_eq!(platform, ".OO\n#.O\nO..".parse().unwrap());
+    /// assert_eq!(moved, 2);
+    /// platform.tilt(Direction::Left, |&c| c == 'O', |&c| c == '#');
+    /// assert_eq!(platform, "OO.\n#O.\nO..".parse().unwrap());
+    /// ```
+    pub fn tilt<F, G>(&mut self, direction: Direction, mut is_movable: F, mut is_blocker: G) -> usize
+    where
+        F: FnMut(&T) -> bool,
+        G: FnMut(&T) -> bool,
+    {
+        let (width, height) = (self.width as isize, self.height as isize);
+        // every line is walked starting from the cell the movable cells slide towards
+        let (starts, step, len): (Vec<isize>, isize, isize) = match direction {
+            Direction::Up => ((0..width).collect(), width, height),
+            Direction::Down => ((0..width).map(|x| (height - 1) * width + x).collect(), -width, height),
+            Direction::Left => ((0..height).map(|y| y * width).collect(), 1, width),
+            Direction::Right => ((0..height).map(|y| y * width + width - 1).collect(), -1, width),
+        };
+        let mut moved = 0;
+        for start in starts {
+            let mut free = 0;
+            for i in 0..len {
+                let index = (start + i * step) as usize;
+                if is_blocker(&self.data[index]) {
+                    free = i + 1;
+                } else if is_movable(&self.data[index]) {
+                    if free != i {
+                        self.data.swap(index, (start + free * step) as usize);
+                        moved += 1;
+                    }
+                    free += 1;
+                }
+            }
+        }
+        moved
+    }
+
+    /// cyclically moves the cells of row y n steps to the right, cells that fall off the end come
+    /// back on the other side. negative n moves them to the left. panics if y is out of range
+    /// ```
+    /// use point_index::*;
+    /// let mut grid: Grid<char> = "abcd\nefgh".parse().unwrap();
+    /// grid.rotate_row(0, 1);
+    /// grid.rotate_row(1, -6);
+    /// assert_eq!(grid, "dabc\nghef".parse().unwrap());
+    /// ```
+    pub fn rotate_row(&mut self, y: usize, n: isize) {
+        let width = self.width;
+        self.row_mut(y).rotate_right(n.rem_euclid(width.max(1) as isize) as usize);
+    }
+
+    /// cyclically moves the cells of column x n steps down, cells that fall off the bottom come
+    /// back at the top. negative n moves them up. panics if x is out of range
+    /// ```
+    /// use point_index::*;
+    /// let mut grid: Grid<char> = "ab\ncd\nef".parse().unwrap();
+    /// grid.rotate_column(1, 1);
+    /// assert_eq!(grid, "af\ncb\ned".parse().unwrap());
+    /// ```
+    pub fn rotate_column(&mut self, x: usize, n: isize) {
+        assert!(x < self.width, "column {x} is out of range for a grid of width {}", self.width);
+        let height = self.height;
+        let k = n.rem_euclid(height.max(1) as isize) as usize;
+        // rotating right by k is reversing everything, then the first k and the rest separately
+        self.reverse_column(x, 0..height);
+        self.reverse_column(x, 0..k);
+        self.reverse_column(x, k..height);
+    }
+
+    /// reverses the order of the cells of column x in the rows of the range
+    fn reverse_column(&mut self, x: usize, rows: Range<usize>) {
+        let (mut top, mut bottom) = (rows.start, rows.end);
+        while top + 1 < bottom {
+            bottom -= 1;
+            self.data.swap(top * self.width + x, bottom * self.width + x);
+            top += 1;
+        }
+    }
+
+    /// the columns and rows of other that are inside the grid when other is placed at offset
+    fn overlap<U>(&self, other: &Grid<U>, offset: Point) -> Option<(Range<usize>, Range<usize>)> {
+        let clip = |shift: isize, inner: usize, outer: usize| {
+            let start = (-shift).clamp(0, inner as isize) as usize;
+            let end = (outer as isize - shift).clamp(0, inner as isize) as usize;
+            start..end.max(start)
+        };
+        let xs = clip(offset.x, other.width, self.width);
+        let ys = clip(offset.y, other.height, self.height);
+        (!xs.is_empty() && !ys.is_empty()).then_some((xs, ys))
+    }
+
+    /// draws the grid into a string, one line per row, writing whatever f returns for each cell
+    /// ```
+    /// use point_index::*;
+    /// let walls = Grid::from_fn(3, 2, |p| p.x == 1);
+    /// assert_eq!(walls.render_with(|&wall| if wall { '#' } else { '.' }), ".#.\n.#.");
+    /// ```
+    pub fn render_with<D: fmt::Display, F: FnMut(&T) -> D>(&self, f: F) -> String {
+        self.map(f).to_string()
+    }
+
+    /// the cells in row major order
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// the cells in row major order
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// gives back the cells in row major order
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// whether every cell of rect is inside the grid
+    pub(crate) fn fits(&self, rect: Rect) -> bool {
+        Rect::new(Point::ZERO, self.width, self.height).contains_rect(rect)
+    }
+
+    /// the position of a point in the flat storage, None if it's outside the grid
+    pub(crate) fn flat_index<P: PrimInt>(&self, point: GenericPoint<P>) -> Option<usize> {
+        let (x, y) = (point.x.to_usize()?, point.y.to_usize()?);
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+}
+
+/// the error returned when parsing a grid fails, either because a row has a different length
+/// than the first one or because a character couldn't be converted
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParseGridError(());
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected rows of equal length containing only valid cells")
+    }
+}
+
+impl Error for ParseGridError {}
+
+/// the error returned when converting a `Vec<Vec<T>>` whose rows don't all have the same length
+/// into a grid
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TryFromVecError(());
+
+impl fmt::Display for TryFromVecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected rows of equal length")
+    }
+}
+
+impl Error for TryFromVecError {}
+
+/// flattens a nested vec into a grid, failing if it's ragged
+/// ```
+/// use point_index::*;
+/// let grid = Grid::try_from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+/// assert_eq!(grid[Point::new(0, 1)], 3);
+/// assert!(Grid::try_from(vec![vec![1, 2], vec![3]]).is_err());
+/// ```
+impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
+    type Error = TryFromVecError;
+
+    fn try_from(vec: Vec<Vec<T>>) -> Result<Grid<T>, TryFromVecError> {
+        let width = vec.first().map_or(0, Vec::len);
+        if vec.iter().any(|row| row.len() != width) {
+            return Err(TryFromVecError(()));
+        }
+        let height = vec.len();
+        Ok(Grid { data: vec.into_iter().flatten().collect(), width, height })
+    }
+}
+
+/// splits a grid back into one vec per row
+/// ```
+/// use point_index::*;
+/// let grid = Grid::from_fn(2, 2, |p| p.x + p.y);
+/// assert_eq!(Vec::from(grid), vec![vec![0, 1], vec![1, 2]]);
+/// ```
+impl<T> From<Grid<T>> for Vec<Vec<T>> {
+    fn from(grid: Grid<T>) -> Vec<Vec<T>> {
+        let mut cells = grid.data.into_iter();
+        (0..grid.height).map(|_| cells.by_ref().take(grid.width).collect()).collect()
+    }
+}
+
+/// writes the grid one line per row with the cells next to each other, so a `Grid<char>` prints
+/// the same text it was parsed from
+/// ```
+/// use point_index::*;
+/// let maze: Grid<char> = "#S#\n#.#".parse().unwrap();
+/// assert_eq!(maze.to_string(), "#S#\n#.#");
+/// assert_eq!(Grid::new(2, 2, 0).to_string(), "00\n00");
+/// ```
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// parses a block of text into a grid of its characters, see [`Grid::parse_with`]
+/// ```
+/// use point_index::*;
+/// let maze: Grid<char> = "
+/// #S#
+/// #.#
+/// ".parse().unwrap();
+/// assert_eq!(maze[Point::new(1, 0)], 'S');
+/// assert_eq!(maze.as_slice().len(), 6);
+/// ```
+impl FromStr for Grid<char> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Grid<char>, ParseGridError> {
+        Grid::parse_with(s, Some)
+    }
+}
+
+impl<T, P: PrimInt> Index<GenericPoint<P>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: GenericPoint<P>) -> &T {
+        let i = self.flat_index(index).expect("point is outside the grid");
+        &self.data[i]
+    }
+}
+
+impl<T, P: PrimInt> IndexMut<GenericPoint<P>> for Grid<T> {
+    fn index_mut(&mut self, index: GenericPoint<P>) -> &mut T {
+        let i = self.flat_index(index).expect("point is outside the grid");
+        &mut self.data[i]
+    }
+}
+
+impl<T, P: PrimInt> Get<GenericPoint<P>> for Grid<T> {
+    type Output = T;
+
+    fn get_option(&self, point: GenericPoint<P>) -> Option<&T> {
+        self.data.get(self.flat_index(point)?)
+    }
+
+    fn get_mut_option(&mut self, point: GenericPoint<P>) -> Option<&mut T> {
+        let i = self.flat_index(point)?;
+        self.data.get_mut(i)
+    }
+}
+
+impl<T, P: PrimInt> Set<GenericPoint<P>> for Grid<T> {
+    type Output = T;
+
+    fn set(&mut self, point: GenericPoint<P>, value: T) -> Option<T> {
+        Some(mem::replace(self.get_mut_option(point)?, value))
+    }
+}
+
+/// counts the cells of the grid
+/// ```
+/// use point_index::*;
+/// let seats: Grid<char> = "#L#\nL##".parse().unwrap();
+/// assert_eq!(seats.count_eq(&'#'), 4);
+/// assert_eq!(seats.count(|&c| c != '#'), 2);
+/// ```
+impl<T> Count for Grid<T> {
+    type Output = T;
+
+    fn count<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> usize {
+        self.data.iter().filter(|cell| predicate(cell)).count()
+    }
+}
+
+/// inserting into a grid without rows or columns sets its width or height to the length of the
+/// new row or column
+/// ```
+/// use point_index::*;
+/// let mut image: Grid<char> = "#.\n..".parse().unwrap();
+/// image.insert_column(1, vec!['.', '.']);
+/// image.insert_row(0, vec!['~'; 3]);
+/// assert_eq!(image, "~~~\n#..\n...".parse().unwrap());
+/// assert_eq!(image.remove_row(2), ['.', '.', '.']);
+/// assert_eq!(image.dims(), Point::new(3, 2));
+/// ```
+impl<T> InsertRemove for Grid<T> {
+    type Output = T;
+
+    fn insert_row(&mut self, y: usize, row: Vec<T>) {
+        assert!(y <= self.height, "row index is outside the grid");
+        if self.height == 0 {
+            self.width = row.len();
+        }
+        assert_eq!(row.len(), self.width, "row length doesn't match the grid width");
+        let start = y * self.width;
+        self.data.splice(start..start, row);
+        self.height += 1;
+    }
+
+    fn insert_column(&mut self, x: usize, column: Vec<T>) {
+        assert!(x <= self.width, "column index is outside the grid");
+        if self.width == 0 {
+            self.height = column.len();
+        }
+        assert_eq!(column.len(), self.height, "column length doesn't match the grid height");
+        let mut data = Vec::with_capacity(self.data.len() + self.height);
+        let mut cells = mem::take(&mut self.data).into_iter();
+        for item in column {
+            data.extend(cells.by_ref().take(x));
+            data.push(item);
+            data.extend(cells.by_ref().take(self.width - x));
+        }
+        self.data = data;
+        self.width += 1;
+    }
+
+    fn remove_row(&mut self, y: usize) -> Vec<T> {
+        assert!(y < self.height, "row index is outside the grid");
+        self.height -= 1;
+        self.data.drain(y * self.width..(y + 1) * self.width).collect()
+    }
+
+    fn remove_column(&mut self, x: usize) -> Vec<T> {
+        assert!(x < self.width, "column index is outside the grid");
+        let mut column = Vec::with_capacity(self.height);
+        let mut data = Vec::with_capacity(self.data.len() - self.height);
+        for (i, cell) in mem::take(&mut self.data).into_iter().enumerate() {
+            if i % self.width == x {
+                column.push(cell);
+            } else {
+                data.push(cell);
+            }
+        }
+        self.data = data;
+        self.width -= 1;
+        column
+    }
+}
+
+/// a grid for tests where about density percent of the cells are true, the same seed always gives
+/// the same grid
+#[cfg(test)]
+pub(crate) fn random_grid(width: usize, height: usize, mut seed: u64, density: u64) -> Grid<bool> {
+    Grid::from_fn(width, height, |_| {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (seed >> 33) % 100 < density
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ALL_DIRECTIONS, DOWN, RIGHT, UP};
+
+    #[test]
+    fn does_not_wrap_rows() {
+        let mut grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.get_option(Point::new(3, 0)), None);
+        assert_eq!(grid.set(Point::new(0, 0) + RIGHT * 2 + DOWN, 1), Some(0));
+        assert_eq!(grid.as_slice()[5], 1);
+        assert_eq!(grid.get_option(Point::new_isize(-1, 1)), None);
+    }
+    #[test]
+    fn parse_edge_cases() {
+        assert_eq!("".parse::<Grid<char>>().map(Grid::into_vec), Ok(vec![]));
+        assert_eq!("ab\r\ncd\n\n".parse::<Grid<char>>().map(Grid::into_vec), Ok(vec!['a', 'b', 'c', 'd']));
+        assert_eq!("ab\nc".parse::<Grid<char>>(), Err(ParseGridError(())));
+        assert_eq!("ab\n\ncd".parse::<Grid<char>>(), Err(ParseGridError(())));
+    }
+    #[test]
+    fn rows_and_columns_of_empty_grids() {
+        let mut wide = Grid::new(3, 0, 0);
+        assert_eq!(wide.rows().count(), 0);
+        assert_eq!(wide.columns().map(|c| c.count()).collect::<Vec<_>>(), [0, 0, 0]);
+        assert_eq!(wide.columns_mut().count(), 3);
+        let tall = Grid::new(0, 3, 0);
+        assert_eq!(tall.columns().count(), 0);
+    }
+    #[test]
+    fn columns_are_the_transpose_of_rows() {
+        let grid = Grid::from_fn(4, 3, |p| p);
+        for (x, column) in grid.columns().enumerate() {
+            assert!(column.enumerate().all(|(y, &p)| p == Point::new(x, y)));
+        }
+        let mut grid = grid;
+        for row in grid.rows_mut() {
+            row.reverse();
+        }
+        assert_eq!(grid.rows().next().unwrap()[0], Point::new(3, 0));
+    }
+    #[test]
+    fn rotations_compose() {
+        let grid = Grid::from_fn(4, 3, |p| p.to_index(4));
+        assert_eq!(grid.rotate_cw().rotate_cw(), grid.rotate_180());
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.rotate_ccw().rotate_ccw().rotate_ccw(), grid.rotate_cw());
+        assert_eq!(Grid::new(0, 2, 0).rotate_cw(), Grid::new(2, 0, 0));
+    }
+    #[test]
+    fn dihedral_group() {
+        let grid = Grid::from_fn(3, 2, |p| p.to_index(3));
+        assert_eq!(grid.flip_horizontal().flip_vertical(), grid.rotate_180());
+        assert_eq!(grid.flip_vertical().flip_vertical(), grid);
+        assert_eq!(grid.rotate_cw().flip_horizontal(), grid.transpose());
+        let mut orientations = Vec::new();
+        let mut current = grid.clone();
+        for _ in 0..4 {
+            orientations.push(current.flip_horizontal());
+            orientations.push(current.clone());
+            current = current.rotate_cw();
+        }
+        assert!(orientations.iter().enumerate().all(|(i, a)| orientations[..i].iter().all(|b| a != b)));
+    }
+    #[test]
+    fn pad_then_crop() {
+        let grid = Grid::from_fn(3, 2, |p| p.x * p.y);
+        let padded = grid.pad(2, -1);
+        assert_eq!(padded.crop(Rect::new(Point::new(2, 2), 3, 2)), Some(grid.clone()));
+        assert_eq!(padded.as_slice().iter().filter(|&&c| c == -1).count(), 7 * 6 - 6);
+        assert!(grid.crop(Rect::new(Point::new_isize(-1, 0), 1, 1)).is_none());
+        assert_eq!(grid.crop(Rect::new(Point::new(3, 2), 0, 0)), Some(Grid::new(0, 0, 0)));
+    }
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = Grid::from_fn(4, 3, |p| p);
+        for point in Point::ZERO.to(Point::new(3, 2)) {
+            assert!(grid.neighbors8(point).all(|(p, &value)| p == value && p.chebyshev_distance(point) == 1));
+            let expected = point.neighbors4().into_iter().filter(|&p| grid.in_bounds(p)).count();
+            assert_eq!(grid.neighbors4(point).count(), expected);
+        }
+        assert_eq!(grid.neighbors8(Point::new_isize(-1, -1)).count(), 1);
+    }
+    #[test]
+    fn step_into_reads_only_old_cells() {
+        let start = Grid::from_fn(6, 4, |p| p.x * 7 + p.y);
+        let shift = |p: Point, g: &Grid<isize>| g[(p + RIGHT).wrap(g.width, g.height)];
+        let mut grid = start.clone();
+        let mut scratch = Grid::new(1, 1, 0);
+        for _ in 0..6 {
+            let expected = grid.step(shift);
+            grid.step_into(&mut scratch, shift);
+            assert_eq!(grid, expected);
+        }
+        assert_eq!(grid, start);
+        assert_eq!(scratch.dims(), start.dims());
+    }
+    #[test]
+    fn scaling_matches_blocks() {
+        let grid = Grid::from_fn(3, 2, |p| p.x + 10 * p.y);
+        let scaled = grid.scale_up(3);
+        assert_eq!(scaled.dims(), Point::new(9, 6));
+        assert_eq!(grid.scale_up_with(|&c| Grid::new(3, 3, c)), Some(scaled));
+        let tall = grid.scale_up_with(|&c| Grid::from_fn(2, 3, |p| c * 100 + p.x + 10 * p.y)).unwrap();
+        assert_eq!(tall.row(4), &[1010, 1011, 1110, 1111, 1210, 1211]);
+        assert_eq!(grid.scale_up(0).dims(), Point::ZERO);
+        assert_eq!(grid.scale_up_with(|_| Grid::new(0, 4, 0)).unwrap().dims(), Point::new(0, 8));
+    }
+    #[test]
+    fn tiles_repeat_every_cell() {
+        let grid = Grid::from_fn(3, 2, |p| p.x + 10 * p.y);
+        let tiled = grid.tile(4, 3);
+        assert_eq!(tiled.dims(), Point::new(12, 6));
+        for (point, &value) in tiled.enumerate() {
+            assert_eq!(value, grid[Point::new_isize(point.x % 3, point.y % 2)]);
+        }
+        let numbered = grid.tile_with(2, 2, |tile, &c| c + 100 * tile.x + 1000 * tile.y);
+        assert_eq!(numbered[Point::new(5, 3)], 1112);
+        assert_eq!(grid.tile(0, 5).dims(), Point::new(0, 10));
+    }
+    #[test]
+    fn blit_clips_at_every_edge() {
+        let sprite = Grid::from_fn(3, 3, |p| p.x + 10 * p.y + 1);
+        for offset in Point::new_isize(-4, -4).to(Point::new(5, 4)) {
+            let mut plain = Grid::new(5, 4, 0);
+            plain.blit(&sprite, offset);
+            let mut masked = Grid::new(5, 4, 0);
+            masked.blit_masked(&sprite, offset, &12);
+            for (point, &cell) in plain.enumerate() {
+                let expected = sprite.get_option(point - offset).copied().unwrap_or(0);
+                assert_eq!(cell, expected);
+                assert_eq!(masked[point], if expected == 12 { 0 } else { expected });
+            }
+        }
+    }
+    #[test]
+    fn shifts_and_rotations_move_cells() {
+        let grid = Grid::from_fn(5, 4, |p| p.x + 10 * p.y);
+        for direction in ALL_DIRECTIONS {
+            for n in 0..7 {
+                let mut shifted = grid.clone();
+                shifted.shift(direction, n, -1);
+                for (point, &cell) in shifted.enumerate() {
+                    let from = point - direction * n as isize;
+                    assert_eq!(cell, grid.get_option(from).copied().unwrap_or(-1));
+                }
+            }
+        }
+        for n in -9..9 {
+            let mut rows = grid.clone();
+            rows.rotate_row(2, n);
+            let mut columns = grid.clone();
+            columns.rotate_column(3, n);
+            for point in Point::ZERO.to(Point::new(4, 3)) {
+                let (mut row_source, mut column_source) = (point, point);
+                if point.y == 2 {
+                    row_source.x = (point.x - n).rem_euclid(5);
+                }
+                if point.x == 3 {
+                    column_source.y = (point.y - n).rem_euclid(4);
+                }
+                assert_eq!(rows[point], grid[row_source]);
+                assert_eq!(columns[point], grid[column_source]);
+            }
+        }
+    }
+    #[test]
+    fn shifts_bigger_than_the_grid_clear_it() {
+        let huge = [(RIGHT, usize::MAX), (Point::new(2, 0), 1 << 62), (Point::new_isize(-3, 5), usize::MAX / 2)];
+        for (direction, n) in huge {
+            let mut grid = Grid::new(3, 3, 1);
+            grid.shift(direction, n, 0);
+            assert_eq!(grid, Grid::new(3, 3, 0));
+        }
+        let mut row = Grid::from_vec(3, 1, vec![1, 2, 3]).unwrap();
+        row.shift(Point::new_isize(-1, 0), 2, 0);
+        assert_eq!(row.as_slice(), &[3, 0, 0]);
+    }
+    #[test]
+    fn tilting_matches_rotated_grids() {
+        let (rocks, walls) = (random_grid(9, 7, 3, 25), random_grid(9, 7, 4, 25));
+        let platform = Grid::from_fn(9, 7, |p| if walls[p] { '#' } else if rocks[p] { 'O' } else { '.' });
+        let (rock, wall) = (|&c: &char| c == 'O', |&c: &char| c == '#');
+        let mut north = platform.clone();
+        let moved = north.tilt(Direction::Up, rock, wall);
+        assert_eq!(north.count_eq(&'O'), platform.count_eq(&'O'));
+        for point in north.find_all(rock) {
+            assert_ne!(north.get_option(point + UP), Some(&'.'));
+        }
+        assert!(moved > 0 && north.tilt(Direction::Up, rock, wall) == 0);
+        for (direction, turns) in [(Direction::Right, 1), (Direction::Down, 2), (Direction::Left, 3)] {
+            let mut tilted = platform.clone();
+            tilted.tilt(direction, rock, wall);
+            let mut turned = platform.clone();
+            for _ in 0..turns {
+                turned = turned.rotate_ccw();
+            }
+            turned.tilt(Direction::Up, rock, wall);
+            for _ in 0..turns {
+                turned = turned.rotate_cw();
+            }
+            assert_eq!(tilted, turned);
+        }
+    }
+    #[test]
+    fn get_many_mut_in_any_order() {
+        let mut grid = Grid::from_fn(3, 3, |p| p.x + 10 * p.y);
+        let [a, b, c] = grid.get_many_mut([Point::new(2, 2), Point::ZERO, Point::new(1, 1)]).unwrap();
+        assert_eq!((*a, *b, *c), (22, 0, 11));
+        *a = -1;
+        assert_eq!(grid[Point::new(2, 2)], -1);
+        assert!(grid.get_many_mut([Point::ONE, Point::ZERO, Point::ONE]).is_none());
+        assert!(grid.get_many_mut([Point::ZERO, Point::new(3, 0)]).is_none());
+    }
+    #[test]
+    fn columns_mut_visits_every_cell_once() {
+        let mut grid = Grid::new(4, 3, 0);
+        let mut columns: Vec<_> = grid.columns_mut().collect();
+        for (x, column) in columns.iter_mut().enumerate().rev() {
+            column.for_each(|cell| *cell += x + 1);
+        }
+        drop(columns);
+        assert_eq!(grid, Grid::from_fn(4, 3, |p| p.x as usize + 1));
+        assert_eq!(Grid::new(0, 3, 0).rows().count(), 0);
+    }
+    #[test]
+    fn columns_of_zero_height_grid_are_empty() {
+        let mut grid = Grid::new(3, 0, 0);
+        assert_eq!(grid.column(1).count(), 0);
+        assert_eq!(grid.column_mut(2).count(), 0);
+        assert!(grid.columns().all(|mut column| column.next().is_none()));
+    }
+    #[test]
+    fn nested_vec_round_trip() {
+        let nested = vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']];
+        let grid = Grid::try_from(nested.clone()).unwrap();
+        assert_eq!(grid, "abc\ndef".parse().unwrap());
+        assert_eq!(Vec::from(grid), nested);
+        let empty_rows: Grid<u8> = Grid::try_from(vec![vec![], vec![]]).unwrap();
+        assert_eq!(empty_rows.dims(), Point::new(0, 2));
+        assert_eq!(Vec::from(empty_rows), vec![Vec::<u8>::new(); 2]);
+    }
+    #[test]
+    fn structural_edits_match_nested_vecs() {
+        let mut grid = Grid::from_fn(4, 3, |p| p.x + 10 * p.y);
+        let mut nested = Vec::from(grid.clone());
+        for (edit, index) in [(0, 2), (1, 3), (2, 0), (3, 3), (1, 0), (0, 2)] {
+            match edit {
+                0 => assert_eq!(grid.remove_row(index), nested.remove_row(index)),
+                1 => assert_eq!(grid.remove_column(index), nested.remove_column(index)),
+                2 => {
+                    let row = vec![-1; grid.width()];
+                    grid.insert_row(index, row.clone());
+                    nested.insert_row(index, row);
+                }
+                _ => {
+                    let column = vec![-2; grid.height()];
+                    grid.insert_column(index, column.clone());
+                    nested.insert_column(index, column);
+                }
+            }
+            assert_eq!(Vec::from(grid.clone()), nested);
+        }
+        let mut empty = Grid::new(0, 0, 0);
+        empty.insert_column(0, vec![1, 2]);
+        assert_eq!(empty.dims(), Point::new(1, 2));
+    }
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let grid = Grid::new(2, 2, 'a');
+        let _ = grid[Point::new(2, 0)];
+    }
+}
@@ -42,8 +42,67 @@
 //! )
 //! ```
 //! quick warning, this library relies on const generics for arrays.
+//!
+//! [`Point`] is an alias for [`GenericPoint<isize>`], any other primitive integer can be used as
+//! the coordinate type if memory or interop matters more than convenience.
+//! ```
+//! use point_index::*;
+//! let vec = vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8]];
+//! let small: GenericPoint<i32> = GenericPoint { x: 2, y: 1 };
+//! assert_eq!(vec[small], 5);
+//! assert_eq!(vec.get_option(GenericPoint::<u8> { x: 0, y: 2 }), Some(&6));
+//! ```
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::iter::Sum;
 use std::mem;
-use std::ops::{Add, Index, IndexMut, Mul, Sub};
+use std::str::FromStr;
+use std::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use num_traits::{Euclid, PrimInt, Signed};
+
+pub mod point3;
+pub use point3::Point3;
+mod direction;
+pub use direction::{
+    parse_instructions, parse_instructions_with, parse_path, trace_path, Direction, InstructionFormat,
+    ParseDirectionError, ParseInstructionError,
+};
+mod walker;
+pub use walker::Walker;
+mod transform;
+pub use transform::{Pose, Rotate, Rotation};
+mod line;
+pub use line::Line;
+mod shape;
+mod rect;
+pub use rect::Rect;
+mod grid;
+pub use grid::{Grid, ParseGridError, TryFromVecError};
+mod view;
+pub use view::{GridView, GridViewMut};
+mod sparse;
+pub use sparse::SparseGrid;
+mod wrapping;
+pub use wrapping::WrappingGrid;
+mod entry;
+pub use entry::Entry;
+mod region;
+pub use region::Connectivity;
+mod path;
+pub use path::astar;
+mod sight;
+pub use sight::RayStep;
+mod filter;
+pub use filter::BorderPolicy;
+mod morph;
+pub use morph::{dilate_points, erode_points, outline_points};
+mod search;
+mod mirror;
+#[cfg(feature = "serde")]
+pub mod compact;
+#[cfg(feature = "rayon")]
+mod parallel;
 
 pub const UP: Point = Point { x: 0, y: -1 };
 pub const DOWN: Point = Point { x: 0, y: 1 };
@@ -54,127 +113,855 @@ pub const UP_RIGHT: Point = Point { x: 1, y: -1 };
 pub const DOWN_LEFT: Point = Point { x: -1, y: 1 };
 pub const DOWN_RIGHT: Point = Point { x: 1, y: 1 };
 
+/// the four orthogonal directions in the same order as [`Point::neighbors4`]
+pub const CARDINALS: [Point; 4] = [UP, DOWN, LEFT, RIGHT];
+/// the four diagonal directions in reading order
+pub const DIAGONALS: [Point; 4] = [UP_LEFT, UP_RIGHT, DOWN_LEFT, DOWN_RIGHT];
+/// all eight directions in reading order, the same order as [`Point::neighbors8`]
+pub const ALL_DIRECTIONS: [Point; 8] = [UP_LEFT, UP, UP_RIGHT, LEFT, RIGHT, DOWN_LEFT, DOWN, DOWN_RIGHT];
+
 
+/// a point generic over its coordinate type, most of the time you want [`Point`]
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub struct Point {
-    pub x: isize,
-    pub y: isize,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericPoint<T> {
+    pub x: T,
+    pub y: T,
 }
 
+/// the default point type, uses isize so that it can go negative when adding directions
+pub type Point = GenericPoint<isize>;
+
+/// a compact point that takes half the memory of [`Point`] on 64 bit targets, useful for very
+/// large collections of points. it supports the same operators, conversions and container
+/// indexing as [`Point`], convert it with `Point::from` to use the isize only helpers
+/// ```
+/// use point_index::*;
+/// use std::collections::HashSet;
+/// let vec = vec![vec![0, 1, 2], vec![3, 4, 5]];
+/// let visited: HashSet<Point32> = [Point32 { x: 2, y: 1 }].into_iter().collect();
+/// let point = *visited.iter().next().unwrap();
+/// assert_eq!(vec[point], 5);
+/// assert_eq!(Point::from(point).neighbors4()[0], Point::new(2, 0));
+/// assert_eq!(Point32::try_from(Point::new(2, 1)), Ok(point));
+/// ```
+pub type Point32 = GenericPoint<i32>;
+
 impl Point {
+    /// the origin
+    pub const ZERO: Point = Point { x: 0, y: 0 };
+    /// the point with both coordinates set to one, the same as DOWN_RIGHT
+    pub const ONE: Point = Point { x: 1, y: 1 };
+
     /// creates a new point from two usizes, if you want to create a point from two isize,
     /// use Point{x: x, y: y}
     /// or Point::new_isize(x, y)
-    pub fn new(x: usize, y: usize) -> Point {
+    pub const fn new(x: usize, y: usize) -> Point {
         Point {x: x as isize, y: y as isize}
     }
     /// creates a new point from two isize, if you want to create a point from two usize,
     /// use Point::new(x, y)
-    pub fn new_isize(x: isize, y: isize) -> Point {
+    pub const fn new_isize(x: isize, y: isize) -> Point {
         Point {x, y}
     }
+    /// the same as `self + other` but usable in const contexts
+    /// ```
+    /// use point_index::*;
+    /// const KNIGHT_MOVES: [Point; 8] = [
+    ///     UP.mul_const(2).add_const(LEFT), UP.mul_const(2).add_const(RIGHT),
+    ///     RIGHT.mul_const(2).add_const(UP), RIGHT.mul_const(2).add_const(DOWN),
+    ///     DOWN.mul_const(2).add_const(RIGHT), DOWN.mul_const(2).add_const(LEFT),
+    ///     LEFT.mul_const(2).add_const(DOWN), LEFT.mul_const(2).add_const(UP),
+    /// ];
+    /// assert_eq!(KNIGHT_MOVES[0], Point::new_isize(-1, -2));
+    /// ```
+    pub const fn add_const(self, other: Point) -> Point {
+        Point::new_isize(self.x + other.x, self.y + other.y)
+    }
+    /// the same as `self - other` but usable in const contexts
+    pub const fn sub_const(self, other: Point) -> Point {
+        Point::new_isize(self.x - other.x, self.y - other.y)
+    }
+    /// the same as `self * other` but usable in const contexts
+    pub const fn mul_const(self, other: isize) -> Point {
+        Point::new_isize(self.x * other, self.y * other)
+    }
+    /// converts the point into an index into a flat row major array with the given width, returns
+    /// None if the point is negative or x is not smaller than the width
+    /// ```
+    /// use point_index::*;
+    /// let flat = vec![0, 1, 2, 3, 4, 5];
+    /// assert_eq!(flat[Point::new(1, 1).to_index(3).unwrap()], 4);
+    /// assert_eq!(Point::new(3, 0).to_index(3), None);
+    /// ```
+    pub fn to_index(self, width: usize) -> Option<usize> {
+        let (x, y) = self.to_usize_pair()?;
+        if x >= width {
+            return None;
+        }
+        y.checked_mul(width)?.checked_add(x)
+    }
+    /// the inverse of to_index, turns an index into a flat row major array with the given width
+    /// back into a point, panics if width is zero
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::from_index(4, 3), Point::new(1, 1));
+    /// ```
+    pub const fn from_index(index: usize, width: usize) -> Point {
+        assert!(width > 0, "can't index into a grid with a width of zero");
+        Point::new(index % width, index / width)
+    }
+    /// wraps the point into a width by height grid, coordinates that fall off one side come back
+    /// in on the other, negative coordinates included. panics if either dimension is zero or
+    /// bigger than isize::MAX
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!((Point::new(0, 0) + LEFT).wrap(5, 3), Point::new(4, 0));
+    /// assert_eq!(Point::new(7, 3).wrap(5, 3), Point::new(2, 0));
+    /// ```
+    pub fn wrap(self, width: usize, height: usize) -> Point {
+        let (Ok(w), Ok(h)) = (isize::try_from(width), isize::try_from(height)) else {
+            panic!("can't wrap a point into a grid bigger than isize::MAX");
+        };
+        assert!(w > 0 && h > 0, "can't wrap a point into an empty grid");
+        Point::new_isize(self.x.rem_euclid(w), self.y.rem_euclid(h))
+    }
+    /// snaps the point to the nearest cell inside a width by height grid, panics if either
+    /// dimension is zero
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new_isize(-4, 2).clamp_to_bounds(5, 3), Point::new(0, 2));
+    /// assert_eq!(Point::new(9, 9).clamp_to_bounds(5, 3), Point::new(4, 2));
+    /// ```
+    pub fn clamp_to_bounds(self, width: usize, height: usize) -> Point {
+        assert!(width > 0 && height > 0, "can't clamp a point into an empty grid");
+        self.clamp(Point::ZERO, Point::new(width - 1, height - 1))
+    }
+    /// returns the four orthogonal neighbors of the point in the order up, down, left, right
+    /// ```
+    /// use point_index::*;
+    /// let vec = vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8]];
+    /// let sum: i32 = Point::new(1, 1).neighbors4().into_iter().map(|p| vec[p]).sum();
+    /// assert_eq!(sum, 1 + 7 + 3 + 5);
+    /// ```
+    pub fn neighbors4(self) -> [Point; 4] {
+        CARDINALS.map(|direction| self + direction)
+    }
+    /// returns all eight surrounding points in reading order, left to right, top to bottom
+    /// ```
+    /// use point_index::*;
+    /// let grid = vec![vec![1, 0, 1], vec![0, 1, 1], vec![0, 0, 1]];
+    /// let alive = Point::new(1, 1).neighbors8().into_iter().filter(|&p| grid[p] == 1).count();
+    /// assert_eq!(alive, 4);
+    /// ```
+    pub fn neighbors8(self) -> [Point; 8] {
+        ALL_DIRECTIONS.map(|direction| self + direction)
+    }
+    /// the manhattan (taxicab) distance between two points. the difference of each coordinate is
+    /// computed with abs_diff so it can't overflow, and the sum saturates at usize::MAX
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new(1, 1).manhattan_distance(Point::new_isize(-2, 5)), 7);
+    /// ```
+    pub fn manhattan_distance(self, other: Point) -> usize {
+        self.x.abs_diff(other.x).saturating_add(self.y.abs_diff(other.y))
+    }
+    /// the chebyshev distance between two points, the number of king moves it takes to get from
+    /// one to the other
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new(1, 1).chebyshev_distance(Point::new_isize(-2, 5)), 4);
+    /// ```
+    pub fn chebyshev_distance(self, other: Point) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+    /// the squared euclidean distance between two points, useful for comparing distances without
+    /// floating point math. saturates at usize::MAX like manhattan_distance
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new(1, 1).distance_squared(Point::new_isize(-2, 5)), 25);
+    /// ```
+    pub fn distance_squared(self, other: Point) -> usize {
+        let dx = self.x.abs_diff(other.x);
+        let dy = self.y.abs_diff(other.y);
+        dx.saturating_mul(dx).saturating_add(dy.saturating_mul(dy))
+    }
+    /// the point halfway between self and other, coordinates that land between two cells are
+    /// rounded down (towards negative infinity) so the result doesn't depend on the argument order
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new(0, 0).midpoint(Point::new(4, 3)), Point::new(2, 1));
+    /// assert_eq!(Point::new(4, 3).midpoint(Point::new(0, 0)), Point::new(2, 1));
+    /// ```
+    pub fn midpoint(self, other: Point) -> Point {
+        self.lerp(other, 1, 2)
+    }
+    /// the point num / den of the way from self to other, so 0 / den is self and den / den is
+    /// other. like midpoint this rounds down towards negative infinity whatever the signs of num
+    /// and den are, panics if den is zero
+    /// ```
+    /// use point_index::*;
+    /// let start = Point::new(0, 0);
+    /// let end = Point::new(9, 3);
+    /// assert_eq!(start.lerp(end, 1, 3), Point::new(3, 1));
+    /// assert_eq!(start.lerp(end, 1, 2), Point::new(4, 1));
+    /// assert_eq!(start.lerp(end, 3, 3), end);
+    /// ```
+    pub fn lerp(self, other: Point, num: isize, den: isize) -> Point {
+        // div_euclid only rounds down for a positive divisor
+        let (num, den) = if den < 0 { (-num, -den) } else { (num, den) };
+        self + ((other - self) * num).div_euclid(den)
+    }
+    /// whether the three points lie on one straight line
+    /// ```
+    /// use point_index::*;
+    /// assert!(Point::collinear(Point::new(0, 0), Point::new(2, 1), Point::new(6, 3)));
+    /// assert!(!Point::collinear(Point::new(0, 0), Point::new(2, 1), Point::new(6, 4)));
+    /// ```
+    pub fn collinear(a: Point, b: Point, c: Point) -> bool {
+        (b - a).cross(c - a) == 0
+    }
+    /// divides the vector by the greatest common divisor of its coordinates, giving the smallest
+    /// integer step in the same direction. the origin stays the origin. the only divisor too big
+    /// for an isize is 2^63, which only happens when every coordinate is 0 or isize::MIN, and
+    /// those reduce to their signs
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new_isize(6, -4).reduced(), Point::new_isize(3, -2));
+    /// assert_eq!(Point::new(0, 5).reduced(), DOWN);
+    /// ```
+    pub fn reduced(self) -> Point {
+        let (mut a, mut b) = (self.x.unsigned_abs(), self.y.unsigned_abs());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        if a == 0 {
+            return self;
+        }
+        match isize::try_from(a) {
+            Ok(gcd) => self / gcd,
+            Err(_) => self.signum(),
+        }
+    }
+    /// the single step, diagonals included, that moves self towards other. returns the origin if
+    /// the points are the same
+    /// ```
+    /// use point_index::*;
+    /// let tail = Point::new(1, 3);
+    /// assert_eq!(tail.direction_to(Point::new(3, 2)), UP_RIGHT);
+    /// assert_eq!(tail.direction_to(tail), Point::ZERO);
+    /// ```
+    pub fn direction_to(self, other: Point) -> Point {
+        (other - self).signum()
+    }
+    /// the cardinal direction that moves self towards other along the axis where they are furthest
+    /// apart, ties go to the horizontal axis. returns None if the points are the same
+    /// ```
+    /// use point_index::*;
+    /// let chaser = Point::new(1, 3);
+    /// assert_eq!(chaser.cardinal_direction_to(Point::new(2, 0)), Some(Direction::Up));
+    /// assert_eq!(chaser.cardinal_direction_to(Point::new(0, 2)), Some(Direction::Left));
+    /// ```
+    pub fn cardinal_direction_to(self, other: Point) -> Option<Direction> {
+        let delta = other - self;
+        let step = if delta.x.abs() >= delta.y.abs() {
+            Point::new_isize(delta.x.signum(), 0)
+        } else {
+            Point::new_isize(0, delta.y.signum())
+        };
+        Direction::from_point(step)
+    }
+    /// rotates the point 90 degrees clockwise around the origin. since y grows downwards this
+    /// turns up into right, right into down and so on
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(UP.rotate_cw(), RIGHT);
+    /// assert_eq!(UP_RIGHT.rotate_cw(), DOWN_RIGHT);
+    /// ```
+    pub const fn rotate_cw(self) -> Point {
+        Point::new_isize(-self.y, self.x)
+    }
+    /// rotates the point 90 degrees counter clockwise around the origin, the inverse of rotate_cw
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(UP.rotate_ccw(), LEFT);
+    /// assert_eq!(Point::new(2, 1).rotate_ccw().rotate_cw(), Point::new(2, 1));
+    /// ```
+    pub const fn rotate_ccw(self) -> Point {
+        Point::new_isize(self.y, -self.x)
+    }
+    /// rotates the point around a pivot by a number of quarter turns, positive turns are clockwise
+    /// and negative turns are counter clockwise
+    /// ```
+    /// use point_index::*;
+    /// let pivot = Point::new(1, 1);
+    /// let piece = [Point::new(0, 1), Point::new(1, 1), Point::new(2, 1)];
+    /// let rotated = piece.map(|p| p.rotate_around(pivot, 1));
+    /// assert_eq!(rotated, [Point::new(1, 0), Point::new(1, 1), Point::new(1, 2)]);
+    /// assert_eq!(Point::new(2, 1).rotate_around(pivot, -1), Point::new(1, 0));
+    /// ```
+    pub fn rotate_around(self, pivot: Point, quarter_turns: i32) -> Point {
+        let offset = self - pivot;
+        let rotated = match quarter_turns.rem_euclid(4) {
+            0 => offset,
+            1 => offset.rotate_cw(),
+            2 => offset.rotate_cw().rotate_cw(),
+            _ => offset.rotate_ccw(),
+        };
+        pivot + rotated
+    }
+    /// mirrors the x coordinate around the origin, turning left into right
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(UP_LEFT.reflect_x(), UP_RIGHT);
+    /// ```
+    pub fn reflect_x(self) -> Point {
+        Point::new_isize(-self.x, self.y)
+    }
+    /// mirrors the y coordinate around the origin, turning up into down
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(UP_LEFT.reflect_y(), DOWN_LEFT);
+    /// ```
+    pub fn reflect_y(self) -> Point {
+        Point::new_isize(self.x, -self.y)
+    }
+    /// swaps the x and y coordinates, mirroring the point along the main diagonal
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new(1, 2).swap_xy(), Point::new(2, 1));
+    /// ```
+    pub fn swap_xy(self) -> Point {
+        Point::new_isize(self.y, self.x)
+    }
+    /// mirrors the point over the vertical line at column axis_x
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new(1, 3).reflect_over_x(4), Point::new(7, 3));
+    /// ```
+    pub fn reflect_over_x(self, axis_x: isize) -> Point {
+        Point::new_isize(2 * axis_x - self.x, self.y)
+    }
+    /// mirrors the point over the horizontal line at row axis_y
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new(1, 3).reflect_over_y(1), Point::new_isize(1, -1));
+    /// ```
+    pub fn reflect_over_y(self, axis_y: isize) -> Point {
+        Point::new_isize(self.x, 2 * axis_y - self.y)
+    }
+}
+
+impl<T: PrimInt> GenericPoint<T> {
+    /// creates a point with both coordinates set to the same value
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::splat(3), Point::new(3, 3));
+    /// ```
+    pub fn splat(v: T) -> GenericPoint<T> {
+        GenericPoint{x: v, y: v}
+    }
+    /// converts both coordinates to usize, returns None if either of them is negative or too large
+    fn to_usize_pair(self) -> Option<(usize, usize)> {
+        Some((self.x.to_usize()?, self.y.to_usize()?))
+    }
+    /// adds two points, returns None if either coordinate overflows
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new(1, 1).checked_add(UP), Some(Point::new(1, 0)));
+    /// assert_eq!(Point::new_isize(isize::MAX, 0).checked_add(RIGHT), None);
+    /// ```
+    pub fn checked_add(self, other: GenericPoint<T>) -> Option<GenericPoint<T>> {
+        Some(GenericPoint{x: self.x.checked_add(&other.x)?, y: self.y.checked_add(&other.y)?})
+    }
+    /// subtracts two points, returns None if either coordinate overflows
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(GenericPoint::<u8> { x: 0, y: 1 }.checked_sub(GenericPoint { x: 1, y: 0 }), None);
+    /// ```
+    pub fn checked_sub(self, other: GenericPoint<T>) -> Option<GenericPoint<T>> {
+        Some(GenericPoint{x: self.x.checked_sub(&other.x)?, y: self.y.checked_sub(&other.y)?})
+    }
+    /// multiplies a point by a scalar, returns None if either coordinate overflows
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(DOWN_RIGHT.checked_mul(isize::MAX), Some(DOWN_RIGHT * isize::MAX));
+    /// assert_eq!(UP.checked_mul(isize::MIN), None);
+    /// ```
+    pub fn checked_mul(self, other: T) -> Option<GenericPoint<T>> {
+        Some(GenericPoint{x: self.x.checked_mul(&other)?, y: self.y.checked_mul(&other)?})
+    }
+    /// converts the point to another coordinate type, returns None if either coordinate doesn't fit
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new(3, 4).cast::<u8>(), Some(GenericPoint { x: 3u8, y: 4 }));
+    /// assert_eq!(UP.cast::<u8>(), None);
+    /// ```
+    pub fn cast<U: PrimInt>(self) -> Option<GenericPoint<U>> {
+        Some(GenericPoint{x: U::from(self.x)?, y: U::from(self.y)?})
+    }
+    /// euclidean division of both coordinates, this rounds down for a positive divisor so
+    /// negative coordinates end up in the right cell when downscaling
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new_isize(-3, 3).div_euclid(2), Point::new_isize(-2, 1));
+    /// ```
+    pub fn div_euclid(self, other: T) -> GenericPoint<T> where T: Euclid {
+        GenericPoint{x: self.x.div_euclid(&other), y: self.y.div_euclid(&other)}
+    }
+    /// euclidean remainder of both coordinates, this is never negative
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new_isize(-3, 3).rem_euclid(2), Point::new(1, 1));
+    /// ```
+    pub fn rem_euclid(self, other: T) -> GenericPoint<T> where T: Euclid {
+        GenericPoint{x: self.x.rem_euclid(&other), y: self.y.rem_euclid(&other)}
+    }
+    /// the component-wise minimum of two points, the top left corner of their bounding box
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new(1, 5).min(Point::new(3, 2)), Point::new(1, 2));
+    /// ```
+    pub fn min(self, other: GenericPoint<T>) -> GenericPoint<T> {
+        GenericPoint{x: self.x.min(other.x), y: self.y.min(other.y)}
+    }
+    /// the component-wise maximum of two points, the bottom right corner of their bounding box
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new(1, 5).max(Point::new(3, 2)), Point::new(3, 5));
+    /// ```
+    pub fn max(self, other: GenericPoint<T>) -> GenericPoint<T> {
+        GenericPoint{x: self.x.max(other.x), y: self.y.max(other.y)}
+    }
+    /// clamps each coordinate between the matching coordinates of lo and hi, panics if lo is
+    /// larger than hi on either axis
+    /// ```
+    /// use point_index::*;
+    /// let cursor = Point::new_isize(-2, 7);
+    /// assert_eq!(cursor.clamp(Point::new(0, 0), Point::new(4, 4)), Point::new(0, 4));
+    /// ```
+    pub fn clamp(self, lo: GenericPoint<T>, hi: GenericPoint<T>) -> GenericPoint<T> {
+        GenericPoint{x: self.x.clamp(lo.x, hi.x), y: self.y.clamp(lo.y, hi.y)}
+    }
+    /// the absolute value of each coordinate
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new_isize(-3, 2).abs(), Point::new(3, 2));
+    /// ```
+    pub fn abs(self) -> GenericPoint<T> where T: Signed {
+        GenericPoint{x: self.x.abs(), y: self.y.abs()}
+    }
+    /// the sign of each coordinate, this is the single step that moves a point towards a target
+    /// ```
+    /// use point_index::*;
+    /// let head = Point::new(4, 1);
+    /// let tail = Point::new(2, 2);
+    /// assert_eq!((head - tail).signum(), UP_RIGHT);
+    /// ```
+    pub fn signum(self) -> GenericPoint<T> where T: Signed {
+        GenericPoint{x: self.x.signum(), y: self.y.signum()}
+    }
+    /// the dot product of two points treated as vectors
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::new(2, 3).dot(Point::new(4, 5)), 23);
+    /// assert_eq!(UP.dot(RIGHT), 0);
+    /// ```
+    pub fn dot(self, other: GenericPoint<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+    /// the scalar 2d cross product, the z component of the 3d cross product. since y grows
+    /// downwards a positive result means other is clockwise from self
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(UP.cross(RIGHT), 1);
+    /// assert_eq!(UP.cross(LEFT), -1);
+    /// assert_eq!(DOWN_RIGHT.cross(DOWN_RIGHT * 3), 0);
+    /// ```
+    pub fn cross(self, other: GenericPoint<T>) -> T {
+        self.x * other.y - self.y * other.x
+    }
 }
 
-impl Add for Point {
-    type Output = Point;
+impl<T: PrimInt> Add for GenericPoint<T> {
+    type Output = GenericPoint<T>;
 
-    fn add(self, other: Point) -> Point {
-        Point{
+    fn add(self, other: GenericPoint<T>) -> GenericPoint<T> {
+        GenericPoint{
             x: self.x + other.x,
             y: self.y + other.y,
         }
     }
 }
 
-impl Sub for Point {
-    type Output = Point;
+impl<T: PrimInt> Sub for GenericPoint<T> {
+    type Output = GenericPoint<T>;
 
-    fn sub(self, other: Point) -> Point {
-        Point::new_isize(self.x - other.x, self.y - other.y)
+    fn sub(self, other: GenericPoint<T>) -> GenericPoint<T> {
+        GenericPoint{x: self.x - other.x, y: self.y - other.y}
     }
 }
 
-impl Mul<isize> for Point {
-    type Output = Point;
+impl<T: PrimInt> Mul<T> for GenericPoint<T> {
+    type Output = GenericPoint<T>;
 
-    fn mul(self, other: isize) -> Point {
-        Point::new_isize(self.x * other, self.y * other)
+    fn mul(self, other: T) -> GenericPoint<T> {
+        GenericPoint{x: self.x * other, y: self.y * other}
+    }
+}
+
+/// implements an operator for every mix of owned and borrowed operands by copying and forwarding
+/// to the owned implementation, the same way std does it for the primitive types
+macro_rules! forward_ref_binop {
+    ($imp:ident, $method:ident, $rhs:ty) => {
+        impl<'a, T: PrimInt> $imp<$rhs> for &'a GenericPoint<T> {
+            type Output = GenericPoint<T>;
+
+            fn $method(self, other: $rhs) -> GenericPoint<T> {
+                $imp::$method(*self, other)
+            }
+        }
+
+        impl<'a, T: PrimInt> $imp<&'a $rhs> for GenericPoint<T> {
+            type Output = GenericPoint<T>;
+
+            fn $method(self, other: &'a $rhs) -> GenericPoint<T> {
+                $imp::$method(self, *other)
+            }
+        }
+
+        impl<'a, 'b, T: PrimInt> $imp<&'a $rhs> for &'b GenericPoint<T> {
+            type Output = GenericPoint<T>;
+
+            fn $method(self, other: &'a $rhs) -> GenericPoint<T> {
+                $imp::$method(*self, *other)
+            }
+        }
+    };
+}
+
+forward_ref_binop!(Add, add, GenericPoint<T>);
+forward_ref_binop!(Sub, sub, GenericPoint<T>);
+forward_ref_binop!(Mul, mul, T);
+
+/// scales x and y independently, the first element of the pair scales x and the second scales y
+/// ```
+/// use point_index::*;
+/// let cell = Point::new(3, 2);
+/// assert_eq!(cell * (8, 16), Point::new(24, 32));
+/// ```
+impl<T: PrimInt> Mul<(T, T)> for GenericPoint<T> {
+    type Output = GenericPoint<T>;
+
+    fn mul(self, other: (T, T)) -> GenericPoint<T> {
+        GenericPoint{x: self.x * other.0, y: self.y * other.1}
+    }
+}
+
+/// divides both coordinates by a scalar, like integer division this truncates towards zero so
+/// `Point { x: -3, y: 3 } / 2` is `Point { x: -1, y: 1 }`. use div_euclid to round down instead
+impl<T: PrimInt> Div<T> for GenericPoint<T> {
+    type Output = GenericPoint<T>;
+
+    fn div(self, other: T) -> GenericPoint<T> {
+        GenericPoint{x: self.x / other, y: self.y / other}
+    }
+}
+
+/// the remainder of both coordinates divided by a scalar, like integer remainder the result takes
+/// the sign of the coordinate so `Point { x: -3, y: 3 } % 2` is `Point { x: -1, y: 1 }`. use
+/// rem_euclid to always get a non negative result
+impl<T: PrimInt> Rem<T> for GenericPoint<T> {
+    type Output = GenericPoint<T>;
+
+    fn rem(self, other: T) -> GenericPoint<T> {
+        GenericPoint{x: self.x % other, y: self.y % other}
+    }
+}
+
+/// adds up all the points, an empty iterator sums to the origin
+/// ```
+/// use point_index::*;
+/// let moves = vec![UP, UP, RIGHT, DOWN_RIGHT];
+/// let displacement: Point = moves.iter().sum();
+/// assert_eq!(displacement, Point::new_isize(2, -1));
+/// ```
+impl<T: PrimInt> Sum for GenericPoint<T> {
+    fn sum<I: Iterator<Item = GenericPoint<T>>>(iter: I) -> GenericPoint<T> {
+        iter.fold(GenericPoint::splat(T::zero()), |acc, point| acc + point)
+    }
+}
+
+impl<'a, T: PrimInt> Sum<&'a GenericPoint<T>> for GenericPoint<T> {
+    fn sum<I: Iterator<Item = &'a GenericPoint<T>>>(iter: I) -> GenericPoint<T> {
+        iter.copied().sum()
+    }
+}
+
+/// implements scalar * point for each primitive integer so multiplication works in both orders
+macro_rules! impl_scalar_mul {
+    ($($scalar:ty),*) => {
+        $(
+            impl Mul<GenericPoint<$scalar>> for $scalar {
+                type Output = GenericPoint<$scalar>;
+
+                fn mul(self, other: GenericPoint<$scalar>) -> GenericPoint<$scalar> {
+                    other * self
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_mul!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<T: PrimInt> AddAssign for GenericPoint<T> {
+    fn add_assign(&mut self, other: GenericPoint<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: PrimInt> SubAssign for GenericPoint<T> {
+    fn sub_assign(&mut self, other: GenericPoint<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T: PrimInt> MulAssign<T> for GenericPoint<T> {
+    fn mul_assign(&mut self, other: T) {
+        *self = *self * other;
+    }
+}
+
+impl<T: PrimInt + Signed> Neg for GenericPoint<T> {
+    type Output = GenericPoint<T>;
+
+    fn neg(self) -> GenericPoint<T> {
+        GenericPoint{x: -self.x, y: -self.y}
+    }
+}
+
+impl<T> From<(T, T)> for GenericPoint<T> {
+    fn from((x, y): (T, T)) -> GenericPoint<T> {
+        GenericPoint{x, y}
+    }
+}
+
+impl<T> From<[T; 2]> for GenericPoint<T> {
+    fn from([x, y]: [T; 2]) -> GenericPoint<T> {
+        GenericPoint{x, y}
+    }
+}
+
+/// converts unsigned coordinates, like Point::new this does not check for overflow
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Point {
+        Point::new(x, y)
+    }
+}
+
+/// implements the conversions from a point back into a tuple or an array, these can't be generic
+/// because of the orphan rules
+macro_rules! impl_into_tuple {
+    ($($scalar:ty),*) => {
+        $(
+            impl From<GenericPoint<$scalar>> for ($scalar, $scalar) {
+                fn from(point: GenericPoint<$scalar>) -> ($scalar, $scalar) {
+                    (point.x, point.y)
+                }
+            }
+
+            impl From<GenericPoint<$scalar>> for [$scalar; 2] {
+                fn from(point: GenericPoint<$scalar>) -> [$scalar; 2] {
+                    [point.x, point.y]
+                }
+            }
+        )*
+    };
+}
+
+impl_into_tuple!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// points are ordered in reading order, first by y and then by x, so sorting a list of points or
+/// iterating over a BTreeSet of them goes left to right, top to bottom the same way
+/// enumerate_iter_vec does. note that the inherent min, max and clamp methods are component-wise,
+/// use `Ord::min` and friends if you want the smaller point in reading order
+/// ```
+/// use point_index::*;
+/// let mut points = vec![Point::new(0, 1), Point::new(2, 0), Point::new(1, 0)];
+/// points.sort();
+/// assert_eq!(points, [Point::new(1, 0), Point::new(2, 0), Point::new(0, 1)]);
+/// ```
+impl<T: Ord> Ord for GenericPoint<T> {
+    fn cmp(&self, other: &GenericPoint<T>) -> Ordering {
+        self.y.cmp(&other.y).then_with(|| self.x.cmp(&other.x))
     }
 }
 
-impl<A> Index<Point> for Vec<Vec<A>> {
+impl<T: Ord> PartialOrd for GenericPoint<T> {
+    fn partial_cmp(&self, other: &GenericPoint<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// prints the point as `(x, y)`
+/// ```
+/// use point_index::*;
+/// assert_eq!(UP_LEFT.to_string(), "(-1, -1)");
+/// ```
+impl<T: fmt::Display> fmt::Display for GenericPoint<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// the error returned when parsing a point from a string fails
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParsePointError(());
+
+impl fmt::Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a point in the form x,y")
+    }
+}
+
+impl Error for ParsePointError {}
+
+/// parses a point in the form `x,y`, whitespace around the numbers and a surrounding pair of
+/// parentheses are allowed so anything printed with Display can be parsed back
+/// ```
+/// use point_index::*;
+/// assert_eq!("3,4".parse(), Ok(Point::new(3, 4)));
+/// assert_eq!(" ( -1 , 2 ) ".parse(), Ok(Point::new_isize(-1, 2)));
+/// assert!("3,4,5".parse::<Point>().is_err());
+/// ```
+impl<T: FromStr> FromStr for GenericPoint<T> {
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<GenericPoint<T>, ParsePointError> {
+        let s = s.trim();
+        let s = match s.strip_prefix('(') {
+            Some(inner) => inner.strip_suffix(')').ok_or(ParsePointError(()))?,
+            None => s,
+        };
+        let (x, y) = s.split_once(',').ok_or(ParsePointError(()))?;
+        let x = x.trim().parse().map_err(|_| ParsePointError(()))?;
+        let y = y.trim().parse().map_err(|_| ParsePointError(()))?;
+        Ok(GenericPoint{x, y})
+    }
+}
+
+/// the error returned when a point can't be converted because one of its coordinates doesn't fit
+/// in the target type, for example a negative coordinate when converting to usize
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TryFromPointError(());
+
+impl fmt::Display for TryFromPointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "point coordinates are out of range for the target type")
+    }
+}
+
+impl Error for TryFromPointError {}
+
+/// implements the fallible conversion into unsigned coordinates. usize is left out since the
+/// infallible From conversion already covers it
+macro_rules! impl_try_into_usize {
+    ($($scalar:ty),*) => {
+        $(
+            impl TryFrom<GenericPoint<$scalar>> for (usize, usize) {
+                type Error = TryFromPointError;
+
+                fn try_from(point: GenericPoint<$scalar>) -> Result<(usize, usize), TryFromPointError> {
+                    point.to_usize_pair().ok_or(TryFromPointError(()))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_into_usize!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128);
+
+impl From<Point32> for Point {
+    fn from(point: Point32) -> Point {
+        Point::new_isize(point.x as isize, point.y as isize)
+    }
+}
+
+impl TryFrom<Point> for Point32 {
+    type Error = TryFromPointError;
+
+    fn try_from(point: Point) -> Result<Point32, TryFromPointError> {
+        point.cast().ok_or(TryFromPointError(()))
+    }
+}
+
+impl<A, T: PrimInt> Index<GenericPoint<T>> for Vec<Vec<A>> {
     type Output = A;
 
-    fn index(&self, index: Point) -> &A {
-        let x: usize = index.x.try_into().unwrap();
-        let y: usize = index.y.try_into().unwrap();
+    fn index(&self, index: GenericPoint<T>) -> &A {
+        let (x, y) = index.to_usize_pair().unwrap();
         &self[y][x]
     }
 }
 
-impl<A, const SIZE_OUTER: usize, const SIZE_INNER: usize> Index<Point> for [[A; SIZE_INNER]; SIZE_OUTER] {
+impl<A, T: PrimInt, const SIZE_OUTER: usize, const SIZE_INNER: usize> Index<GenericPoint<T>> for [[A; SIZE_INNER]; SIZE_OUTER] {
     type Output = A;
 
-    fn index(&self, index: Point) -> &A {
-        let x: usize = index.x.try_into().unwrap();
-        let y: usize = index.y.try_into().unwrap();
+    fn index(&self, index: GenericPoint<T>) -> &A {
+        let (x, y) = index.to_usize_pair().unwrap();
         &self[y][x]
     }
 }
 
-impl <A> IndexMut<Point> for Vec<Vec<A>> {
-    fn index_mut(&mut self, index: Point) -> &mut A {
-        let x: usize = index.x.try_into().unwrap();
-        let y: usize = index.y.try_into().unwrap();
+impl <A, T: PrimInt> IndexMut<GenericPoint<T>> for Vec<Vec<A>> {
+    fn index_mut(&mut self, index: GenericPoint<T>) -> &mut A {
+        let (x, y) = index.to_usize_pair().unwrap();
         &mut self[y][x]
     }
 }
 
-impl <A, const SIZE_OUTER: usize, const SIZE_INNER: usize> IndexMut<Point> for [[A; SIZE_INNER]; SIZE_OUTER] {
-    fn index_mut(&mut self, index: Point) -> &mut A {
-        let x: usize = index.x.try_into().unwrap();
-        let y: usize = index.y.try_into().unwrap();
+impl <A, T: PrimInt, const SIZE_OUTER: usize, const SIZE_INNER: usize> IndexMut<GenericPoint<T>> for [[A; SIZE_INNER]; SIZE_OUTER] {
+    fn index_mut(&mut self, index: GenericPoint<T>) -> &mut A {
+        let (x, y) = index.to_usize_pair().unwrap();
         &mut self[y][x]
     }
 }
 
 
 /// This trait is used to get a value from a 2d array. If the operation fails, either because the
-/// point is out of bounds or because the conversion from isize to usize fails, None is returned.
-pub trait Get{
+/// point is out of bounds or because the conversion to usize fails, None is returned.
+/// `P` is the point type used for indexing, it defaults to [`Point`].
+pub trait Get<P = Point>{
     type Output;
-    fn get_option(&self, point: Point) -> Option<&Self::Output>;
-    fn get_mut_option(&mut self, point: Point) -> Option<&mut Self::Output>;
+    fn get_option(&self, point: P) -> Option<&Self::Output>;
+    fn get_mut_option(&mut self, point: P) -> Option<&mut Self::Output>;
 }
 
-impl<A> Get for Vec<Vec<A>> {
+impl<A, T: PrimInt> Get<GenericPoint<T>> for Vec<Vec<A>> {
     type Output = A;
 
-    fn get_option(&self, point: Point) -> Option<&Self::Output> {
-        let x: usize = point.x.try_into().ok()?;
-        let y: usize = point.y.try_into().ok()?;
+    fn get_option(&self, point: GenericPoint<T>) -> Option<&Self::Output> {
+        let (x, y) = point.to_usize_pair()?;
         self.get(y)?.get(x)
     }
 
-    fn get_mut_option(&mut self, point: Point) -> Option<&mut Self::Output> {
-        let x: usize = point.x.try_into().ok()?;
-        let y: usize = point.y.try_into().ok()?;
+    fn get_mut_option(&mut self, point: GenericPoint<T>) -> Option<&mut Self::Output> {
+        let (x, y) = point.to_usize_pair()?;
         self.get_mut(y)?.get_mut(x)
     }
 }
 
-impl<A, const SIZE_INNER: usize, const SIZE_OUTER: usize> Get for [[A; SIZE_INNER]; SIZE_OUTER] {
+impl<A, T: PrimInt, const SIZE_INNER: usize, const SIZE_OUTER: usize> Get<GenericPoint<T>> for [[A; SIZE_INNER]; SIZE_OUTER] {
     type Output = A;
 
-    fn get_option(&self, point: Point) -> Option<&Self::Output> {
-        let x: usize = point.x.try_into().ok()?;
-        let y: usize = point.y.try_into().ok()?;
+    fn get_option(&self, point: GenericPoint<T>) -> Option<&Self::Output> {
+        let (x, y) = point.to_usize_pair()?;
         self.get(y)?.get(x)
     }
 
-    fn get_mut_option(&mut self, point: Point) -> Option<&mut Self::Output> {
-        let x: usize = point.x.try_into().ok()?;
-        let y: usize = point.y.try_into().ok()?;
+    fn get_mut_option(&mut self, point: GenericPoint<T>) -> Option<&mut Self::Output> {
+        let (x, y) = point.to_usize_pair()?;
         self.get_mut(y)?.get_mut(x)
     }
 }
@@ -182,34 +969,114 @@ impl<A, const SIZE_INNER: usize, const SIZE_OUTER: usize> Get for [[A; SIZE_INNE
 
 /// This trait is used to set a value in a 2d array if it succeeds then the item that was at that
 /// index is returned. If the operation fails, either because the point is out of bounds or because
-/// the conversion from isize to usize fails, None is returned.
-pub trait Set{
+/// the conversion to usize fails, None is returned.
+/// `P` is the point type used for indexing, it defaults to [`Point`].
+pub trait Set<P = Point>{
     type Output;
-    fn set(&mut self, point: Point, value: Self::Output) -> Option<Self::Output>;
+    fn set(&mut self, point: P, value: Self::Output) -> Option<Self::Output>;
 }
 
-impl<A> Set for Vec<Vec<A>> {
+impl<A, T: PrimInt> Set<GenericPoint<T>> for Vec<Vec<A>> {
     type Output = A;
 
-    fn set(&mut self, point: Point, value: Self::Output) -> Option<Self::Output> {
-        let x: usize = point.x.try_into().ok()?;
-        let y: usize = point.y.try_into().ok()?;
+    fn set(&mut self, point: GenericPoint<T>, value: Self::Output) -> Option<Self::Output> {
+        let (x, y) = point.to_usize_pair()?;
         let inner = self.get_mut(y)?;
         let location = inner.get_mut(x)?;
         Some(mem::replace(location, value))
     }
 }
 
-impl<A, const SIZE_INNER: usize, const SIZE_OUTER: usize> Set for [[A; SIZE_INNER]; SIZE_OUTER] {
+impl<A, T: PrimInt, const SIZE_INNER: usize, const SIZE_OUTER: usize> Set<GenericPoint<T>> for [[A; SIZE_INNER]; SIZE_OUTER] {
     type Output = A;
 
-    fn set(&mut self, point: Point, value: Self::Output) -> Option<Self::Output> {
-        let x: usize = point.x.try_into().ok()?;
-        let y: usize = point.y.try_into().ok()?;
+    fn set(&mut self, point: GenericPoint<T>, value: Self::Output) -> Option<Self::Output> {
+        let (x, y) = point.to_usize_pair()?;
         Some(mem::replace(self.get_mut(y)?.get_mut(x)?, value))
     }
 }
 
+/// This trait is used to count the cells of a 2d array without flattening it by hand.
+/// ```
+/// use point_index::*;
+/// let lights = vec![vec!['#', '.', '#'], vec!['.', '.', '#']];
+/// assert_eq!(lights.count_eq(&'#'), 3);
+/// assert_eq!([[1, 2], [3, 4]].count(|&n| n % 2 == 0), 2);
+/// ```
+pub trait Count {
+    type Output;
+    /// the number of cells that match the predicate
+    fn count<F: FnMut(&Self::Output) -> bool>(&self, predicate: F) -> usize;
+
+    /// the number of cells equal to value
+    fn count_eq(&self, value: &Self::Output) -> usize where Self::Output: PartialEq {
+        self.count(|cell| cell == value)
+    }
+}
+
+impl<A> Count for Vec<Vec<A>> {
+    type Output = A;
+
+    fn count<F: FnMut(&A) -> bool>(&self, mut predicate: F) -> usize {
+        self.iter().flatten().filter(|cell| predicate(cell)).count()
+    }
+}
+
+impl<A, const SIZE_INNER: usize, const SIZE_OUTER: usize> Count for [[A; SIZE_INNER]; SIZE_OUTER] {
+    type Output = A;
+
+    fn count<F: FnMut(&A) -> bool>(&self, mut predicate: F) -> usize {
+        self.iter().flatten().filter(|cell| predicate(cell)).count()
+    }
+}
+
+/// This trait is used to insert and remove whole rows and columns of a 2d container, shifting
+/// everything after them. Like `Vec::insert` and `Vec::remove` the methods panic if the index is
+/// out of range, and inserting panics if the new row or column doesn't have the right length.
+/// ```
+/// use point_index::*;
+/// let mut universe = vec![vec!['#', '.'], vec!['.', '.']];
+/// universe.insert_row(1, vec!['.'; 2]);
+/// universe.insert_column(1, vec!['.'; 3]);
+/// assert_eq!(universe, vec![vec!['#', '.', '.'], vec!['.'; 3], vec!['.'; 3]]);
+/// assert_eq!(universe.remove_column(0), ['#', '.', '.']);
+/// ```
+pub trait InsertRemove {
+    type Output;
+    /// inserts a row so that it ends up at index y
+    fn insert_row(&mut self, y: usize, row: Vec<Self::Output>);
+    /// inserts a column so that it ends up at index x
+    fn insert_column(&mut self, x: usize, column: Vec<Self::Output>);
+    /// removes the row at index y and returns it
+    fn remove_row(&mut self, y: usize) -> Vec<Self::Output>;
+    /// removes the column at index x and returns it from top to bottom
+    fn remove_column(&mut self, x: usize) -> Vec<Self::Output>;
+}
+
+/// rows are not checked against each other, so a ragged vec stays ragged
+impl<A> InsertRemove for Vec<Vec<A>> {
+    type Output = A;
+
+    fn insert_row(&mut self, y: usize, row: Vec<A>) {
+        self.insert(y, row);
+    }
+
+    fn insert_column(&mut self, x: usize, column: Vec<A>) {
+        assert_eq!(column.len(), self.len(), "column length doesn't match the number of rows");
+        for (row, item) in self.iter_mut().zip(column) {
+            row.insert(x, item);
+        }
+    }
+
+    fn remove_row(&mut self, y: usize) -> Vec<A> {
+        self.remove(y)
+    }
+
+    fn remove_column(&mut self, x: usize) -> Vec<A> {
+        self.iter_mut().map(|row| row.remove(x)).collect()
+    }
+}
+
 /// turns a 2d vec into a flat iterator that returns the point and the value at that point
 /// it goes from left to right, top to bottom
 /// eventually I will find a way to implement this as a trait without using box to get a decend speed up
@@ -234,6 +1101,26 @@ pub fn enumerate_iter_arr<'a, A: 'a, const INNER: usize, const OUTER: usize>(arr
     }))
 }
 
+/// swaps the rows and columns of a 2d vec, the item at `vec[y][x]` ends up at `result[x][y]`.
+/// this takes O(width * height) time and allocates a new vec for every column.
+/// panics if the rows don't all have the same length
+/// ```
+/// use point_index::*;
+/// let vec = vec![vec![1, 2, 3], vec![4, 5, 6]];
+/// assert_eq!(transpose_vec(vec), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+/// ```
+pub fn transpose_vec<A>(vec: Vec<Vec<A>>) -> Vec<Vec<A>> {
+    let width = vec.first().map_or(0, Vec::len);
+    assert!(vec.iter().all(|row| row.len() == width), "can't transpose a ragged vec");
+    let mut columns: Vec<Vec<A>> = (0..width).map(|_| Vec::with_capacity(vec.len())).collect();
+    for row in vec {
+        for (column, item) in columns.iter_mut().zip(row) {
+            column.push(item);
+        }
+    }
+    columns
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -284,4 +1171,221 @@ mod tests {
         let point = Point::new(1, 1) + RIGHT * 2;
         assert_eq!(v[point], 0);
     }
+    #[test]
+    fn generic_scalars() {
+        let mut v = vec![vec![0; 3]; 3];
+        let point: GenericPoint<i64> = GenericPoint { x: 2, y: 1 };
+        v[point] = 5;
+        assert_eq!(v[point], 5);
+        assert_eq!(v.set(GenericPoint::<u16> { x: 0, y: 0 }, 3), Some(0));
+        assert_eq!(v.get_option(GenericPoint::<i8> { x: -1, y: 0 }), None);
+        assert_eq!(v.get_option(GenericPoint::<i8> { x: 0, y: 0 }), Some(&3));
+    }
+    #[test]
+    fn neighbors4_out_of_bounds() {
+        let v = [[1; 3]; 3];
+        let count = Point::new(0, 0).neighbors4().into_iter().filter_map(|p| v.get_option(p)).count();
+        assert_eq!(count, 2);
+    }
+    #[test]
+    fn direction_tables() {
+        assert_eq!(CARDINALS.iter().sum::<Point>() + DIAGONALS.iter().sum::<Point>(), Point::ZERO);
+        assert!(CARDINALS.iter().chain(DIAGONALS.iter()).all(|d| ALL_DIRECTIONS.contains(d)));
+    }
+    #[test]
+    fn neighbors8_order() {
+        let neighbors = Point::new(1, 1).neighbors8();
+        assert_eq!(neighbors[0], Point::new(0, 0));
+        assert_eq!(neighbors[3], Point::new(0, 1));
+        assert_eq!(neighbors[7], Point::new(2, 2));
+    }
+    #[test]
+    fn manhattan_distance_extremes() {
+        let a = Point::new_isize(isize::MIN, 0);
+        let b = Point::new_isize(isize::MAX, 0);
+        assert_eq!(a.manhattan_distance(b), usize::MAX);
+        assert_eq!(b.manhattan_distance(b + UP_LEFT * 3), 6);
+    }
+    #[test]
+    fn chebyshev_and_squared_distance() {
+        let origin = Point::new(0, 0);
+        assert_eq!(origin.chebyshev_distance(DOWN_RIGHT * 3 + RIGHT), 4);
+        assert_eq!(origin.distance_squared(DOWN_RIGHT * 3 + RIGHT), 25);
+        assert_eq!(origin.distance_squared(Point::new_isize(isize::MIN, 0)), usize::MAX);
+    }
+    #[test]
+    fn rotation_cycle() {
+        assert_eq!(UP.rotate_cw().rotate_cw(), DOWN);
+        assert_eq!(LEFT.rotate_cw(), UP);
+        assert_eq!(DOWN_LEFT.rotate_ccw(), DOWN_RIGHT);
+        let point = Point::new(3, 2);
+        assert_eq!(point.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), point);
+    }
+    #[test]
+    fn rotate_around_pivot() {
+        let pivot = Point::new(5, 5);
+        let point = Point::new(7, 4);
+        assert_eq!(point.rotate_around(pivot, 0), point);
+        assert_eq!(point.rotate_around(pivot, 4), point);
+        assert_eq!(point.rotate_around(pivot, 2), Point::new(3, 6));
+        assert_eq!(point.rotate_around(pivot, -3), point.rotate_around(pivot, 1));
+    }
+    #[test]
+    fn reflections() {
+        let point = Point::new(2, 5);
+        assert_eq!(point.reflect_x().reflect_x(), point);
+        assert_eq!(point.reflect_x().reflect_y(), point.rotate_cw().rotate_cw());
+        assert_eq!(point.swap_xy().swap_xy(), point);
+        assert_eq!(point.reflect_over_x(2), point);
+        assert_eq!(point.reflect_over_y(0), point.reflect_y());
+    }
+    #[test]
+    fn negation() {
+        assert_eq!(-UP, DOWN);
+        assert_eq!(-UP_LEFT, DOWN_RIGHT);
+        assert_eq!(-GenericPoint::<i32> { x: 3, y: -4 }, GenericPoint { x: -3, y: 4 });
+    }
+    #[test]
+    fn compound_assignment() {
+        let mut pos = Point::new(0, 0);
+        for _ in 0..3 {
+            pos += DOWN_RIGHT;
+        }
+        pos -= RIGHT;
+        assert_eq!(pos, Point::new(2, 3));
+        pos *= 2;
+        assert_eq!(pos, Point::new(4, 6));
+    }
+    #[test]
+    fn scalar_on_the_left() {
+        assert_eq!(3 * DOWN_RIGHT, DOWN_RIGHT * 3);
+        assert_eq!(2u8 * GenericPoint::<u8> { x: 1, y: 4 }, GenericPoint { x: 2, y: 8 });
+    }
+    #[test]
+    fn division_and_remainder() {
+        let point = Point::new_isize(-7, 7);
+        assert_eq!(point / 2, Point::new_isize(-3, 3));
+        assert_eq!(point % 2, Point::new_isize(-1, 1));
+        assert_eq!(point.div_euclid(2), Point::new_isize(-4, 3));
+        assert_eq!(point.rem_euclid(2), Point::new(1, 1));
+        assert_eq!(point.div_euclid(2) * 2 + point.rem_euclid(2), point);
+    }
+    #[test]
+    fn bounding_box() {
+        let points = [Point::new(3, 1), Point::new_isize(-1, 4), Point::new(2, 2)];
+        let top_left = points.iter().fold(points[0], |acc, &p| acc.min(p));
+        let bottom_right = points.iter().fold(points[0], |acc, &p| acc.max(p));
+        assert_eq!(top_left, Point::new_isize(-1, 1));
+        assert_eq!(bottom_right, Point::new(3, 4));
+    }
+    #[test]
+    fn reference_operators() {
+        let moves = [UP, RIGHT, RIGHT];
+        let start = Point::new(1, 1);
+        let end = moves.iter().fold(start, |acc, step| acc + step);
+        assert_eq!(end, Point::new(3, 0));
+        let doubled: Vec<Point> = moves.iter().map(|step| step * 2).collect();
+        assert_eq!(doubled, [UP * 2, RIGHT * 2, RIGHT * 2]);
+        let deltas: Vec<Point> = moves.iter().zip(doubled.iter()).map(|(a, b)| b - a).collect();
+        assert_eq!(deltas, moves);
+    }
+    #[test]
+    fn sum_of_points() {
+        let empty: Vec<Point> = Vec::new();
+        assert_eq!(empty.into_iter().sum::<Point>(), Point::ZERO);
+        let total: Point = Point::new(1, 1).neighbors8().into_iter().sum();
+        assert_eq!(total, Point::ONE * 8);
+    }
+    #[test]
+    fn tuple_and_array_conversions() {
+        let parsed: Vec<(isize, isize)> = vec![(1, 2), (-3, 4)];
+        let points: Vec<Point> = parsed.iter().map(|&t| t.into()).collect();
+        assert_eq!(points, [Point::new(1, 2), Point::new_isize(-3, 4)]);
+        assert_eq!(Point::from((5usize, 6usize)), Point::new(5, 6));
+        assert_eq!(Point::from([7, -8]), Point::new_isize(7, -8));
+        let tuple: (isize, isize) = UP.into();
+        let array: [isize; 2] = DOWN_LEFT.into();
+        assert_eq!(tuple, (0, -1));
+        assert_eq!(array, [-1, 1]);
+    }
+    #[test]
+    fn try_into_unsigned() {
+        assert_eq!(<(usize, usize)>::try_from(Point::new(3, 4)), Ok((3, 4)));
+        assert_eq!(<(usize, usize)>::try_from(UP), Err(TryFromPointError(())));
+        let big = GenericPoint::<i128> { x: i128::MAX, y: 0 };
+        assert!(<(usize, usize)>::try_from(big).is_err());
+    }
+    #[test]
+    fn display_round_trip() {
+        let point = Point::new_isize(-12, 7);
+        assert_eq!(point.to_string().parse(), Ok(point));
+        assert_eq!("(1,2".parse::<Point>(), Err(ParsePointError(())));
+        assert_eq!("1 2".parse::<Point>(), Err(ParsePointError(())));
+        assert_eq!("300,1".parse::<GenericPoint<u8>>(), Err(ParsePointError(())));
+    }
+    #[test]
+    fn reading_order() {
+        use std::collections::BTreeSet;
+        let set: BTreeSet<Point> = Point::new(1, 1).neighbors8().into_iter().collect();
+        let ordered: Vec<Point> = set.into_iter().collect();
+        assert_eq!(ordered, Point::new(1, 1).neighbors8());
+        assert!(Point::new(5, 0) < Point::new(0, 1));
+        assert_eq!(Ord::max(Point::new(5, 0), Point::new(0, 1)), Point::new(0, 1));
+    }
+    #[test]
+    fn flat_index_round_trip() {
+        for index in 0..20 {
+            assert_eq!(Point::from_index(index, 7).to_index(7), Some(index));
+        }
+        assert_eq!(LEFT.to_index(7), None);
+        assert_eq!(Point::new_isize(0, isize::MAX).to_index(4), None);
+    }
+    #[test]
+    fn point32_parity() {
+        assert_eq!(mem::size_of::<Point32>(), 8);
+        let mut arr = [[0; 4]; 4];
+        let mut pos = Point32 { x: 0, y: 0 };
+        pos += Point32 { x: 1, y: 1 } * 2;
+        arr[pos] = 1;
+        assert_eq!(arr.set(-pos + pos * 2, 2), Some(1));
+        assert_eq!(Point32::try_from(Point::new_isize(i32::MAX as isize + 1, 0)), Err(TryFromPointError(())));
+    }
+    #[test]
+    fn line_of_sight() {
+        let station = Point::new(3, 4);
+        let asteroids = [Point::new(1, 0), Point::new(2, 2), Point::new(5, 8), Point::new(4, 4)];
+        let far = asteroids.iter().filter(|&&a| (a - station).reduced() != a - station).count();
+        assert_eq!(far, 2);
+        assert!(Point::collinear(station, Point::new(1, 0), Point::new(2, 2)));
+        assert!(!Point::collinear(station, Point::new(1, 0), Point::new(4, 4)));
+        assert_eq!(Point::ZERO.reduced(), Point::ZERO);
+    }
+    #[test]
+    fn reduce_extreme_vectors() {
+        assert_eq!(Point::new_isize(isize::MIN, 0).reduced(), LEFT);
+        assert_eq!(Point::new_isize(isize::MIN, isize::MIN).reduced(), UP_LEFT);
+        assert_eq!(Point::new_isize(0, isize::MIN).reduced(), UP);
+        assert_eq!(Point::new_isize(isize::MIN, 2).reduced(), Point::new_isize(isize::MIN / 2, 1));
+        assert_eq!(Point::new_isize(isize::MAX, isize::MAX).reduced(), DOWN_RIGHT);
+    }
+    #[test]
+    fn lerp_rounds_down_for_any_sign() {
+        let end = Point::new(1, 3);
+        assert_eq!(Point::ZERO.lerp(end, 1, -2), Point::new_isize(-1, -2));
+        assert_eq!(Point::ZERO.lerp(end, -1, 2), Point::new_isize(-1, -2));
+        assert_eq!(Point::ZERO.lerp(end, -1, -2), Point::new(0, 1));
+        assert_eq!(Point::ZERO.lerp(end, 1, 2), Point::new(0, 1));
+        assert_eq!(end.lerp(Point::ZERO, -2, -2), Point::ZERO);
+    }
+    #[test]
+    fn wrap_far_away() {
+        let point = Point::new_isize(-23, 41);
+        assert_eq!(point.wrap(10, 10), Point::new(7, 1));
+        assert_eq!(point.wrap(1, 1), Point::ZERO);
+    }
+    #[test]
+    #[should_panic(expected = "empty grid")]
+    fn wrap_into_empty_grid() {
+        Point::ONE.wrap(0, 3);
+    }
 }
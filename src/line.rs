@@ -0,0 +1,175 @@
+use std::iter::{self, FusedIterator};
+use crate::Point;
+
+impl Point {
+    /// iterates over the cells on the line from self to other, both ends included, using
+    /// bresenham's algorithm. consecutive cells always touch, diagonally or orthogonally
+    /// ```
+    /// use point_index::*;
+    /// let line: Vec<Point> = Point::new(0, 0).line_to(Point::new(4, 2)).collect();
+    /// assert_eq!(line, [
+    ///     Point::new(0, 0), Point::new(1, 1), Point::new(2, 1), Point::new(3, 2), Point::new(4, 2),
+    /// ]);
+    /// ```
+    pub fn line_to(self, other: Point) -> Line {
+        let dx = (other.x - self.x).abs();
+        let dy = -(other.y - self.y).abs();
+        Line {
+            current: self,
+            step: (other - self).signum(),
+            dx,
+            dy,
+            err: dx + dy,
+            remaining: self.chebyshev_distance(other) + 1,
+        }
+    }
+
+    /// iterates over the cells from self to other, both ends included, but only if the two points
+    /// share a row, a column or a diagonal. returns None for any other pair of points
+    /// ```
+    /// use point_index::*;
+    /// let vent: Vec<Point> = Point::new(1, 1).points_between(Point::new(3, 3)).unwrap().collect();
+    /// assert_eq!(vent, [Point::new(1, 1), Point::new(2, 2), Point::new(3, 3)]);
+    /// assert!(Point::new(1, 1).points_between(Point::new(3, 2)).is_none());
+    /// ```
+    pub fn points_between(self, other: Point) -> Option<impl Iterator<Item = Point>> {
+        let delta = other - self;
+        if delta.x != 0 && delta.y != 0 && delta.x.abs() != delta.y.abs() {
+            return None;
+        }
+        let step = delta.signum();
+        Some((0..=self.chebyshev_distance(other)).map(move |i| self + step * i as isize))
+    }
+
+    /// iterates over every cell touched by the segment between the centers of self and other,
+    /// both ends included. unlike line_to this never skips a cell when the segment cuts a corner of
+    /// it, and when the segment passes exactly through a grid corner all the cells around that
+    /// corner are yielded, the horizontal neighbor first
+    /// ```
+    /// use point_index::*;
+    /// let cells: Vec<Point> = Point::new(0, 0).supercover_to(Point::new(3, 1)).collect();
+    /// assert_eq!(cells, [
+    ///     Point::new(0, 0), Point::new(1, 0), Point::new(2, 0),
+    ///     Point::new(1, 1), Point::new(2, 1), Point::new(3, 1),
+    /// ]);
+    /// ```
+    pub fn supercover_to(self, other: Point) -> impl Iterator<Item = Point> {
+        let delta = other - self;
+        let (nx, ny) = (delta.x.abs(), delta.y.abs());
+        let step = delta.signum();
+        let (mut ix, mut iy) = (0, 0);
+        let mut current = self;
+        let steps = iter::from_fn(move || {
+            if ix >= nx && iy >= ny {
+                return None;
+            }
+            // compares when the segment crosses the next vertical and horizontal cell borders
+            let decision = (1 + 2 * ix) * ny - (1 + 2 * iy) * nx;
+            if decision == 0 {
+                let side_x = current + Point::new_isize(step.x, 0);
+                let side_y = current + Point::new_isize(0, step.y);
+                current += step;
+                ix += 1;
+                iy += 1;
+                Some([Some(side_x), Some(side_y), Some(current)])
+            } else if decision < 0 {
+                current.x += step.x;
+                ix += 1;
+                Some([Some(current), None, None])
+            } else {
+                current.y += step.y;
+                iy += 1;
+                Some([Some(current), None, None])
+            }
+        });
+        iter::once([Some(self), None, None]).chain(steps).flat_map(|cells| cells.into_iter().flatten())
+    }
+}
+
+/// an iterator over the cells of a bresenham line, created by [`Point::line_to`]
+#[derive(Debug, Clone)]
+pub struct Line {
+    current: Point,
+    step: Point,
+    dx: isize,
+    dy: isize,
+    err: isize,
+    remaining: usize,
+}
+
+impl Iterator for Line {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let point = self.current;
+        let e2 = 2 * self.err;
+        if e2 >= self.dy {
+            self.err += self.dy;
+            self.current.x += self.step.x;
+        }
+        if e2 <= self.dx {
+            self.err += self.dx;
+            self.current.y += self.step.y;
+        }
+        Some(point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for Line {}
+
+impl FusedIterator for Line {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_is_connected_in_every_octant() {
+        let center = Point::new(10, 10);
+        for end in center.neighbors8().map(|p| center + (p - center) * 3 + (p - center).rotate_cw()) {
+            let line: Vec<Point> = center.line_to(end).collect();
+            assert_eq!(line.first(), Some(&center));
+            assert_eq!(line.last(), Some(&end));
+            assert!(line.windows(2).all(|w| w[0].chebyshev_distance(w[1]) == 1));
+        }
+    }
+    #[test]
+    fn segments_match_lines() {
+        let start = Point::new(5, 5);
+        for direction in crate::ALL_DIRECTIONS {
+            let end = start + direction * 4;
+            let segment: Vec<Point> = start.points_between(end).unwrap().collect();
+            assert_eq!(segment, start.line_to(end).collect::<Vec<_>>());
+        }
+        assert_eq!(start.points_between(start).unwrap().count(), 1);
+    }
+    #[test]
+    fn supercover_diagonal_includes_corners() {
+        let cells: Vec<Point> = Point::new(0, 0).supercover_to(Point::new(2, 2)).collect();
+        assert_eq!(cells.len(), 7);
+        assert!(cells.windows(2).all(|w| w[0].manhattan_distance(w[1]) <= 2));
+        let straight: Vec<Point> = Point::new(4, 0).supercover_to(Point::new(0, 0)).collect();
+        assert_eq!(straight, Point::new(4, 0).line_to(Point::new(0, 0)).collect::<Vec<_>>());
+    }
+    #[test]
+    fn supercover_contains_bresenham() {
+        let start = Point::new_isize(-3, 2);
+        let end = Point::new(7, 5);
+        let cells: Vec<Point> = start.supercover_to(end).collect();
+        assert!(start.line_to(end).all(|p| cells.contains(&p)));
+        assert!(cells.windows(2).all(|w| w[0] != w[1]));
+    }
+    #[test]
+    fn single_point_line() {
+        let point = Point::new(3, 3);
+        assert_eq!(point.line_to(point).collect::<Vec<_>>(), [point]);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::Grid;
+
+impl<T: PartialEq> Grid<T> {
+    /// every horizontal line the grid is mirrored across, given by the number of rows above it,
+    /// together with how many cells don't match their reflection. rows that would be reflected
+    /// outside the grid are ignored, and lines with more than max_mismatches are left out, so 0
+    /// finds only perfect mirrors
+    /// ```
+    /// use point_index::*;
+    /// let pattern: Grid<char> = "
+    /// ..x.
+    /// .x..
+    /// .x..
+    /// ..x.
+    /// ....
+    /// ".parse().unwrap();
+    /// assert_eq!(pattern.horizontal_mirrors(0), [(2, 0)]);
+    /// assert_eq!(pattern.horizontal_mirrors(1), [(2, 0), (4, 1)]);
+    /// ```
+    pub fn horizontal_mirrors(&self, max_mismatches: usize) -> Vec<(usize, usize)> {
+        (1..self.height)
+            .filter_map(|axis| {
+                let mut mismatches = 0;
+                for (above, below) in (0..axis).rev().zip(axis..self.height) {
+                    mismatches += count_mismatches(self.row(above), self.row(below));
+                    if mismatches > max_mismatches {
+                        return None;
+                    }
+                }
+                Some((axis, mismatches))
+            })
+            .collect()
+    }
+
+    /// every vertical line the grid is mirrored across, given by the number of columns left of
+    /// it, together with how many cells don't match their reflection. works like
+    /// [`Grid::horizontal_mirrors`]
+    /// ```
+    /// use point_index::*;
+    /// let pattern: Grid<char> = "
+    /// .xx..
+    /// x..x.
+    /// ".parse().unwrap();
+    /// assert_eq!(pattern.vertical_mirrors(0), [(2, 0)]);
+    /// assert_eq!(pattern.vertical_mirrors(2), [(1, 2), (2, 0), (4, 1)]);
+    /// ```
+    pub fn vertical_mirrors(&self, max_mismatches: usize) -> Vec<(usize, usize)> {
+        (1..self.width)
+            .filter_map(|axis| {
+                let half = axis.min(self.width - axis);
+                let mut mismatches = 0;
+                for row in self.rows() {
+                    let left = row[axis - half..axis].iter().rev();
+                    mismatches += left.zip(&row[axis..axis + half]).filter(|(a, b)| a != b).count();
+                    if mismatches > max_mismatches {
+                        return None;
+                    }
+                }
+                Some((axis, mismatches))
+            })
+            .collect()
+    }
+}
+
+/// the number of places where the slices differ
+fn count_mismatches<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    a.iter().zip(b).filter(|(a, b)| a != b).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+
+    #[test]
+    fn mirrors_match_transposed_grid() {
+        let grid = Grid::from_fn(7, 10, |p| (p.x * p.x + (2 * p.y - 9).abs() * 5) % 3);
+        assert_eq!(grid.horizontal_mirrors(0), [(5, 0)]);
+        for k in 0..6 {
+            assert_eq!(grid.horizontal_mirrors(k), grid.transpose().vertical_mirrors(k));
+            assert_eq!(grid.vertical_mirrors(k), grid.transpose().horizontal_mirrors(k));
+        }
+        let mut smudged = grid.clone();
+        smudged[Point::new(3, 8)] += 1;
+        assert!(smudged.horizontal_mirrors(0).is_empty());
+        assert_eq!(smudged.horizontal_mirrors(1), [(5, 1)]);
+        assert!(Grid::new(1, 1, 0).horizontal_mirrors(3).is_empty());
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::HashSet;
+use crate::{Connectivity, Get, Grid, Point};
+
+impl Grid<bool> {
+    /// a new grid where every cell within n steps of a true cell is true, steps go to the
+    /// neighbors given by connectivity, so shapes grow into diamonds or squares
+    /// ```
+    /// use point_index::*;
+    /// let mut dot = Grid::new(5, 5, false);
+    /// dot[Point::new(2, 2)] = true;
+    /// assert_eq!(dot.dilate(1, Connectivity::Four).count_eq(&true), 5);
+    /// assert_eq!(dot.dilate(1, Connectivity::Eight).count_eq(&true), 9);
+    /// assert_eq!(dot.dilate(2, Connectivity::Four).count_eq(&true), 13);
+    /// ```
+    pub fn dilate(&self, n: usize, connectivity: Connectivity) -> Grid<bool> {
+        let mut grown = self.clone();
+        spread(&mut grown, n, connectivity);
+        grown
+    }
+
+    /// a new grid where a cell stays true only if every cell within n steps of it is true, the
+    /// opposite of [`Grid::dilate`]. cells outside the grid count as false, so shapes also shrink
+    /// away from the edges
+    /// ```
+    /// use point_index::*;
+    /// let block = Grid::new(5, 4, true);
+    /// let eroded = block.erode(1, Connectivity::Four);
+    /// let inside: Vec<Point> = eroded.find_all(|&c| c).collect();
+    /// assert_eq!(inside, Point::new(1, 1).to(Point::new(3, 2)).collect::<Vec<_>>());
+    /// assert_eq!(block.erode(2, Connectivity::Eight).count_eq(&true), 0);
+    /// ```
+    pub fn erode(&self, n: usize, connectivity: Connectivity) -> Grid<bool> {
+        let mut outside = self.map(|&c| !c).pad(1, true);
+        spread(&mut outside, n, connectivity);
+        Grid::from_fn(self.width, self.height, |p| !outside[p + Point::ONE])
+    }
+
+    /// a new grid holding only the true cells that touch a false cell or the edge of the grid,
+    /// through the neighbors given by connectivity
+    /// ```
+    /// use point_index::*;
+    /// let square = Grid::new(3, 3, true);
+    /// let outline = square.outline(Connectivity::Four);
+    /// assert_eq!(outline.to_string(), "truetruetrue\ntruefalsetrue\ntruetruetrue");
+    /// ```
+    pub fn outline(&self, connectivity: Connectivity) -> Grid<bool> {
+        let inner = self.erode(1, connectivity);
+        self.map_with_point(|p, &c| c && !inner[p])
+    }
+}
+
+/// marks every cell within n steps of a true cell as true, one layer at a time
+fn spread(grid: &mut Grid<bool>, n: usize, connectivity: Connectivity) {
+    let mut frontier: Vec<Point> = grid.find_all(|&c| c).collect();
+    for _ in 0..n {
+        let mut next = Vec::new();
+        for point in frontier {
+            for &direction in connectivity.directions() {
+                if grid.get_option(point + direction) == Some(&false) {
+                    grid[point + direction] = true;
+                    next.push(point + direction);
+                }
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        frontier = next;
+    }
+}
+
+/// every point within n steps of a point in the set, like [`Grid::dilate`] without the edges of a
+/// grid to stop it
+/// ```
+/// use point_index::*;
+/// use std::collections::HashSet;
+/// let points = HashSet::from([Point::ZERO, Point::new(5, 0)]);
+/// assert_eq!(dilate_points(&points, 2, Connectivity::Eight).len(), 50);
+/// ```
+pub fn dilate_points(points: &HashSet<Point>, n: usize, connectivity: Connectivity) -> HashSet<Point> {
+    let mut grown = points.clone();
+    let mut frontier: Vec<Point> = points.iter().copied().collect();
+    for _ in 0..n {
+        let mut next = Vec::new();
+        for point in frontier {
+            for &direction in connectivity.directions() {
+                if grown.insert(point + direction) {
+                    next.push(point + direction);
+                }
+            }
+        }
+        frontier = next;
+    }
+    grown
+}
+
+/// the points of the set whose every point within n steps is also in the set, the opposite of
+/// [`dilate_points`]
+/// ```
+/// use point_index::*;
+/// use std::collections::HashSet;
+/// let square: HashSet<Point> = Point::ZERO.to(Point::new(4, 4)).collect();
+/// let core = erode_points(&square, 2, Connectivity::Four);
+/// assert_eq!(core, HashSet::from([Point::new(2, 2)]));
+/// ```
+pub fn erode_points(points: &HashSet<Point>, n: usize, connectivity: Connectivity) -> HashSet<Point> {
+    let mut kept = points.clone();
+    let mut frontier: Vec<Point> = outline_points(points, connectivity).into_iter().collect();
+    for _ in 0..n {
+        frontier.retain(|point| kept.remove(point));
+        let mut next = Vec::new();
+        for point in frontier {
+            next.extend(connectivity.directions().iter().map(|&direction| point + direction));
+        }
+        frontier = next;
+    }
+    kept
+}
+
+/// the points of the set that have a neighbor outside of the set
+/// ```
+/// use point_index::*;
+/// use std::collections::HashSet;
+/// let square: HashSet<Point> = Point::ZERO.to(Point::new(2, 2)).collect();
+/// assert_eq!(outline_points(&square, Connectivity::Eight).len(), 8);
+/// ```
+pub fn outline_points(points: &HashSet<Point>, connectivity: Connectivity) -> HashSet<Point> {
+    points
+        .iter()
+        .copied()
+        .filter(|&point| {
+            connectivity.directions().iter().any(|&direction| !points.contains(&(point + direction)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grids_and_point_sets_agree() {
+        let shape = Grid::from_fn(16, 14, |p| {
+            (4..12).contains(&p.x) && (4..9).contains(&p.y) && p != Point::new(7, 6) || p == Point::new(8, 10)
+        });
+        let points: HashSet<Point> = shape.find_all(|&c| c).collect();
+        let to_set = |grid: &Grid<bool>| grid.find_all(|&c| c).collect::<HashSet<Point>>();
+        for connectivity in [Connectivity::Four, Connectivity::Eight] {
+            for n in 0..4 {
+                assert_eq!(to_set(&shape.dilate(n, connectivity)), dilate_points(&points, n, connectivity));
+                let eroded = shape.erode(n, connectivity);
+                assert_eq!(to_set(&eroded), erode_points(&points, n, connectivity));
+                assert!(to_set(&eroded.dilate(n, connectivity)).is_subset(&points));
+            }
+            assert_eq!(to_set(&shape.outline(connectivity)), outline_points(&points, connectivity));
+        }
+        let edge = Grid::new(4, 3, true);
+        let inside = HashSet::from([Point::new(1, 1), Point::new(2, 1)]);
+        assert_eq!(to_set(&edge.erode(1, Connectivity::Eight)), inside);
+        assert_eq!(edge.dilate(5, Connectivity::Four), edge);
+    }
+}
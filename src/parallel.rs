@@ -0,0 +1,43 @@
+use rayon::prelude::*;
+use crate::{Grid, Point};
+
+impl<T: Sync> Grid<T> {
+    /// a parallel iterator over every cell and its point, rayon splits the work between threads
+    /// ```
+    /// use point_index::*;
+    /// use rayon::prelude::*;
+    /// let grid = Grid::from_fn(100, 100, |p| p.x * p.y);
+    /// let diagonal: isize = grid.par_iter().filter(|(p, _)| p.x == p.y).map(|(_, &v)| v).sum();
+    /// assert_eq!(diagonal, (0..100).map(|i| i * i).sum());
+    /// ```
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = (Point, &T)> {
+        let width = self.width;
+        self.data.par_iter().enumerate().map(move |(i, cell)| (Point::from_index(i, width), cell))
+    }
+
+    /// creates a new grid by calling f on every cell in parallel
+    /// ```
+    /// use point_index::*;
+    /// let grid = Grid::from_fn(3, 2, |p| p.x);
+    /// assert_eq!(grid.par_map(|&x| x * 2), grid.map(|&x| x * 2));
+    /// ```
+    pub fn par_map<U: Send, F: Fn(&T) -> U + Sync + Send>(&self, f: F) -> Grid<U> {
+        let data = self.data.par_iter().map(f).collect();
+        Grid { data, width: self.width, height: self.height }
+    }
+}
+
+impl<T: Send> Grid<T> {
+    /// a parallel iterator over every cell and its point that allows changing the cells
+    /// ```
+    /// use point_index::*;
+    /// use rayon::prelude::*;
+    /// let mut grid = Grid::new(4, 4, 0);
+    /// grid.par_iter_mut().for_each(|(p, cell)| *cell = p.x + p.y);
+    /// assert_eq!(grid, Grid::from_fn(4, 4, |p| p.x + p.y));
+    /// ```
+    pub fn par_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = (Point, &mut T)> {
+        let width = self.width;
+        self.data.par_iter_mut().enumerate().map(move |(i, cell)| (Point::from_index(i, width), cell))
+    }
+}
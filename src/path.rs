@@ -0,0 +1,596 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use crate::{Connectivity, Get, Grid, Point, DOWN, LEFT, RIGHT, UP};
+
+impl<T> Grid<T> {
+    /// the shortest orthogonal path from start to goal that only steps on cells that pass the
+    /// predicate, both ends included. start itself doesn't need to pass. returns None if start is
+    /// outside the grid or goal can't be reached
+    /// ```
+    /// use point_index::*;
+    /// let maze: Grid<char> = "
+    /// S.#
+    /// #.#
+    /// ..E
+    /// ".parse().unwrap();
+    /// let path = maze.bfs(Point::ZERO, Point::new(2, 2), |&c| c != '#').unwrap();
+    /// assert_eq!(path, [
+    ///     Point::new(0, 0), Point::new(1, 0), Point::new(1, 1), Point::new(1, 2), Point::new(2, 2),
+    /// ]);
+    /// assert_eq!(maze.bfs(Point::ZERO, Point::new(2, 0), |&c| c != '#'), None);
+    /// ```
+    pub fn bfs<F: FnMut(&T) -> bool>(&self, start: Point, goal: Point, mut passable: F) -> Option<Vec<Point>> {
+        self.get_option(start)?;
+        let mut parents: Grid<Option<Point>> = Grid::new(self.width, self.height, None);
+        parents[start] = Some(start);
+        let mut queue = VecDeque::from([start]);
+        while let Some(point) = queue.pop_front() {
+            if point == goal {
+                return Some(walk_back(start, goal, |p| parents[p].expect("every reached point has a parent")));
+            }
+            for (next, cell) in self.neighbors4(point) {
+                if parents[next].is_none() && passable(cell) {
+                    parents[next] = Some(point);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// the number of orthogonal steps from start to every cell, only stepping on cells that pass
+    /// the predicate. cells that can't be reached are None. start itself doesn't need to pass
+    /// ```
+    /// use point_index::*;
+    /// let maze: Grid<char> = "
+    /// ..#
+    /// #.#
+    /// ...
+    /// ".parse().unwrap();
+    /// let distances = maze.bfs_all_distances(Point::ZERO, |&c| c != '#');
+    /// assert_eq!(distances[Point::new(2, 2)], Some(4));
+    /// assert_eq!(distances[Point::new(2, 0)], None);
+    /// ```
+    pub fn bfs_all_distances<F: FnMut(&T) -> bool>(&self, start: Point, mut passable: F) -> Grid<Option<usize>> {
+        let mut distances = Grid::new(self.width, self.height, None);
+        if self.get_option(start).is_none() {
+            return distances;
+        }
+        distances[start] = Some(0);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((point, distance)) = queue.pop_front() {
+            for (next, cell) in self.neighbors4(point) {
+                if distances[next].is_none() && passable(cell) {
+                    distances[next] = Some(distance + 1);
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    /// the number of orthogonal steps from every cell to the nearest source, only stepping on cells
+    /// that pass the predicate, also known as a dijkstra map. cells that no source can reach are
+    /// None, sources outside the grid are ignored and sources themselves don't need to pass
+    /// ```
+    /// use point_index::*;
+    /// let town: Grid<char> = "
+    /// S...
+    /// .##.
+    /// ...S
+    /// ".parse().unwrap();
+    /// let shops = town.find_all(|&c| c == 'S');
+    /// let to_shop = town.distance_field(shops, |&c| c != '#');
+    /// assert_eq!(to_shop[Point::new(1, 0)], Some(1));
+    /// assert_eq!(to_shop[Point::new(0, 2)], Some(2));
+    /// assert_eq!(to_shop[Point::new(1, 1)], None);
+    /// // fleeing means stepping to the neighbor with the largest distance
+    /// let flee = town.neighbors4(Point::new(2, 0)).map(|(p, _)| p).max_by_key(|&p| to_shop[p]);
+    /// assert_eq!(flee, Some(Point::new(3, 0)));
+    /// ```
+    pub fn distance_field<I, F>(&self, sources: I, mut passable: F) -> Grid<Option<u32>>
+    where
+        I: IntoIterator<Item = Point>,
+        F: FnMut(&T) -> bool,
+    {
+        let mut distances = Grid::new(self.width, self.height, None);
+        let mut queue = VecDeque::new();
+        for source in sources {
+            if let Some(distance @ None) = distances.get_mut_option(source) {
+                *distance = Some(0);
+                queue.push_back((source, 0));
+            }
+        }
+        while let Some((point, distance)) = queue.pop_front() {
+            for (next, cell) in self.neighbors4(point) {
+                if distances[next].is_none() && passable(cell) {
+                    distances[next] = Some(distance + 1);
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    /// the cheapest path from start to goal and its cost, where cost gives the price of stepping
+    /// onto a cell or None if it can't be entered. this is [`astar`] without a heuristic
+    /// ```
+    /// use point_index::*;
+    /// let risk = Grid::parse_with("116\n138\n213", |c| c.to_digit(10)).unwrap();
+    /// let cost = |&r: &u32| Some(r as usize);
+    /// let (path, total) = risk.dijkstra(Point::ZERO, Point::new(2, 2), Connectivity::Four, cost).unwrap();
+    /// assert_eq!((path.len(), total), (5, 7));
+    /// ```
+    pub fn dijkstra<F: FnMut(&T) -> Option<usize>>(
+        &self,
+        start: Point,
+        goal: Point,
+        connectivity: Connectivity,
+        cost: F,
+    ) -> Option<(Vec<Point>, usize)> {
+        astar(self, start, goal, connectivity, cost, |_, _| 0)
+    }
+
+    /// the cost of the cheapest path from start to every cell, where cost gives the price of
+    /// stepping onto a cell or None if it can't be entered. cells that can't be reached are None
+    /// ```
+    /// use point_index::*;
+    /// let risk = Grid::parse_with("116\n138\n213", |c| c.to_digit(10)).unwrap();
+    /// let costs = risk.dijkstra_costs(Point::ZERO, Connectivity::Four, |&r| Some(r as usize));
+    /// assert_eq!(costs.row(0), &[Some(0), Some(1), Some(7)]);
+    /// assert_eq!(costs[Point::new(2, 2)], Some(7));
+    /// ```
+    pub fn dijkstra_costs<F: FnMut(&T) -> Option<usize>>(
+        &self,
+        start: Point,
+        connectivity: Connectivity,
+        mut cost: F,
+    ) -> Grid<Option<usize>> {
+        let mut costs = Grid::new(self.width, self.height, None);
+        if self.get_option(start).is_none() {
+            return costs;
+        }
+        costs[start] = Some(0);
+        let mut open = BinaryHeap::from([Reverse((0, start))]);
+        while let Some(Reverse((so_far, point))) = open.pop() {
+            if costs[point].is_some_and(|known| so_far > known) {
+                continue;
+            }
+            for &direction in connectivity.directions() {
+                let next = point + direction;
+                let Some(step) = self.get_option(next).and_then(&mut cost) else {
+                    continue;
+                };
+                let next_cost = so_far.saturating_add(step);
+                if !matches!(costs[next], Some(known) if known <= next_cost) {
+                    costs[next] = Some(next_cost);
+                    open.push(Reverse((next_cost, next)));
+                }
+            }
+        }
+        costs
+    }
+}
+
+impl<T> Grid<T> {
+    /// the shortest path from start to goal using jump point search, which finds the same path
+    /// lengths as [`Grid::bfs`] or [`astar`] with a cost of one per step but skips over long
+    /// stretches of open cells instead of queueing every one of them, so it is much faster on
+    /// large open maps. only cells that pass the predicate are stepped on, start itself doesn't
+    /// need to pass. with [`Connectivity::Eight`] a diagonal step costs the same as a straight one
+    /// and can squeeze between two blocked cells, like in [`astar`]
+    /// ```
+    /// use point_index::*;
+    /// let map: Grid<char> = "
+    /// ..........
+    /// ....#.....
+    /// ....#.....
+    /// ....#.....
+    /// ..........
+    /// ".parse().unwrap();
+    /// let goal = Point::new(9, 2);
+    /// let path = map.jps(Point::new(0, 2), goal, Connectivity::Eight, |&c| c == '.').unwrap();
+    /// assert_eq!(path.len(), 10);
+    /// assert_eq!(map.jps(Point::new(0, 2), goal, Connectivity::Four, |&c| c == '.').unwrap().len(), 14);
+    /// ```
+    pub fn jps<F: FnMut(&T) -> bool>(
+        &self,
+        start: Point,
+        goal: Point,
+        connectivity: Connectivity,
+        mut passable: F,
+    ) -> Option<Vec<Point>> {
+        self.get_option(start)?;
+        let mut free = |point: Point| self.get_option(point).is_some_and(&mut passable);
+        let distance: fn(Point, Point) -> usize = match connectivity {
+            Connectivity::Four => Point::manhattan_distance,
+            Connectivity::Eight => Point::chebyshev_distance,
+        };
+        let mut best: Grid<Option<(usize, Point)>> = Grid::new(self.width, self.height, None);
+        best[start] = Some((0, start));
+        let mut open = BinaryHeap::from([Reverse((distance(start, goal), 0, start))]);
+        while let Some(Reverse((_, so_far, point))) = open.pop() {
+            let (known, parent) = best[point].expect("every queued point has a cost");
+            if point == goal {
+                let jump_points = walk_back(start, goal, |p| best[p].expect("every reached point has a parent").1);
+                let mut path = vec![start];
+                for pair in jump_points.windows(2) {
+                    let segment = pair[0].points_between(pair[1]).expect("jump points are on a line");
+                    path.extend(segment.skip(1));
+                }
+                return Some(path);
+            }
+            if so_far > known {
+                continue;
+            }
+            for direction in jump_directions(point, parent, connectivity, &mut free) {
+                let Some(next) = jump(point, direction, goal, connectivity, &mut free) else {
+                    continue;
+                };
+                let next_cost = so_far + distance(point, next);
+                if !matches!(best[next], Some((known, _)) if known <= next_cost) {
+                    best[next] = Some((next_cost, point));
+                    open.push(Reverse((next_cost + distance(next, goal), next_cost, next)));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<T> Grid<T> {
+    /// an any angle path from start to goal using theta*, it searches like a* over the eight
+    /// neighbors but connects every cell straight to the parent of its parent whenever the line
+    /// between them is clear. a line is clear if every cell of its supercover passes the predicate,
+    /// so paths never cut the corner of a blocked cell. returns the corners of the path from start
+    /// to goal and its euclidean length, or None if start is outside the grid or goal can't be
+    /// reached. start itself doesn't need to pass
+    /// ```
+    /// use point_index::*;
+    /// let floor: Grid<char> = "
+    /// ..........
+    /// ..........
+    /// ...####...
+    /// ..........
+    /// ".parse().unwrap();
+    /// let (corners, length) = floor.theta_star(Point::new(0, 0), Point::new(9, 1), |&c| c == '.').unwrap();
+    /// assert_eq!(corners, [Point::new(0, 0), Point::new(9, 1)]);
+    /// assert!((length - 82f64.sqrt()).abs() < 1e-9);
+    /// let (corners, _) = floor.theta_star(Point::new(4, 0), Point::new(4, 3), |&c| c == '.').unwrap();
+    /// assert_eq!(corners, [Point::new(4, 0), Point::new(2, 1), Point::new(2, 3), Point::new(4, 3)]);
+    /// ```
+    pub fn theta_star<F: FnMut(&T) -> bool>(
+        &self,
+        start: Point,
+        goal: Point,
+        mut passable: F,
+    ) -> Option<(Vec<Point>, f64)> {
+        self.get_option(start)?;
+        let mut clear = |from: Point, to: Point| {
+            from.supercover_to(to).skip(1).all(|p| self.get_option(p).is_some_and(&mut passable))
+        };
+        let distance = |a: Point, b: Point| (a.distance_squared(b) as f64).sqrt();
+        let mut best: Grid<Option<(f64, Point)>> = Grid::new(self.width, self.height, None);
+        best[start] = Some((0.0, start));
+        let mut open = BinaryHeap::from([Waypoint { estimate: distance(start, goal), so_far: 0.0, point: start }]);
+        while let Some(Waypoint { so_far, point, .. }) = open.pop() {
+            let (known, parent) = best[point].expect("every queued point has a cost");
+            if point == goal {
+                let corners = walk_back(start, goal, |p| best[p].expect("every reached point has a parent").1);
+                return Some((corners, so_far));
+            }
+            if so_far > known {
+                continue;
+            }
+            for next in point.neighbors8() {
+                if !self.in_bounds(next) || !clear(point, next) {
+                    continue;
+                }
+                let (from, from_cost) = if clear(parent, next) {
+                    (parent, best[parent].expect("parents have a cost").0)
+                } else {
+                    (point, so_far)
+                };
+                let next_cost = from_cost + distance(from, next);
+                if !matches!(best[next], Some((known, _)) if known <= next_cost) {
+                    best[next] = Some((next_cost, from));
+                    let estimate = next_cost + distance(next, goal);
+                    open.push(Waypoint { estimate, so_far: next_cost, point: next });
+                }
+            }
+        }
+        None
+    }
+}
+
+/// an entry of the theta* queue, ordered so the smallest estimate is popped first
+#[derive(Debug, Clone, Copy)]
+struct Waypoint {
+    estimate: f64,
+    so_far: f64,
+    point: Point,
+}
+
+impl Ord for Waypoint {
+    fn cmp(&self, other: &Waypoint) -> Ordering {
+        other.estimate.total_cmp(&self.estimate).then_with(|| other.point.cmp(&self.point))
+    }
+}
+
+impl PartialOrd for Waypoint {
+    fn partial_cmp(&self, other: &Waypoint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Waypoint {
+    fn eq(&self, other: &Waypoint) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Waypoint {}
+
+/// the directions worth searching from a jump point reached from parent, every direction for the
+/// start and otherwise the natural and forced neighbors of the move
+fn jump_directions<F: FnMut(Point) -> bool>(
+    point: Point,
+    parent: Point,
+    connectivity: Connectivity,
+    free: &mut F,
+) -> Vec<Point> {
+    if point == parent {
+        return connectivity.directions().to_vec();
+    }
+    let d = (point - parent).signum();
+    let (horizontal, vertical) = (Point::new_isize(d.x, 0), Point::new_isize(0, d.y));
+    match connectivity {
+        Connectivity::Four if d.y != 0 => vec![d, LEFT, RIGHT],
+        Connectivity::Four => {
+            let mut directions = vec![d];
+            let forced = [UP, DOWN].into_iter().filter(|&side| free(point + side) && !free(point - d + side));
+            directions.extend(forced);
+            directions
+        }
+        Connectivity::Eight if d.x != 0 && d.y != 0 => {
+            let mut directions = vec![horizontal, vertical, d];
+            if !free(point - horizontal) {
+                directions.push(vertical - horizontal);
+            }
+            if !free(point - vertical) {
+                directions.push(horizontal - vertical);
+            }
+            directions
+        }
+        Connectivity::Eight => {
+            let mut directions = vec![d];
+            let forced = [d.rotate_cw(), d.rotate_ccw()].into_iter().filter(|&side| !free(point + side));
+            directions.extend(forced.map(|side| d + side));
+            directions
+        }
+    }
+}
+
+/// steps from point in direction until it reaches the goal or a jump point, a cell where the
+/// search has to branch. moves that never turn on their own (vertical ones when four connected,
+/// diagonal ones when eight connected) also stop where one of their components would reach a
+/// jump point
+fn jump<F: FnMut(Point) -> bool>(
+    point: Point,
+    direction: Point,
+    goal: Point,
+    connectivity: Connectivity,
+    free: &mut F,
+) -> Option<Point> {
+    let (horizontal, vertical) = (Point::new_isize(direction.x, 0), Point::new_isize(0, direction.y));
+    let mut current = point;
+    loop {
+        let next = current + direction;
+        if !free(next) {
+            return None;
+        }
+        if next == goal {
+            return Some(next);
+        }
+        let branches = match connectivity {
+            Connectivity::Four if direction.x == 0 => {
+                jump(next, LEFT, goal, connectivity, free).is_some()
+                    || jump(next, RIGHT, goal, connectivity, free).is_some()
+            }
+            Connectivity::Four => [UP, DOWN].into_iter().any(|side| free(next + side) && !free(current + side)),
+            Connectivity::Eight if direction.x != 0 && direction.y != 0 => {
+                (!free(next - horizontal) && free(next - horizontal + vertical))
+                    || (!free(next - vertical) && free(next - vertical + horizontal))
+                    || jump(next, horizontal, goal, connectivity, free).is_some()
+                    || jump(next, vertical, goal, connectivity, free).is_some()
+            }
+            Connectivity::Eight => [direction.rotate_cw(), direction.rotate_ccw()]
+                .into_iter()
+                .any(|side| !free(next + side) && free(next + side + direction)),
+        };
+        if branches {
+            return Some(next);
+        }
+        current = next;
+    }
+}
+
+/// the cheapest path from start to goal in any container that implements [`Get`], and its cost,
+/// using a* search. cost gives the price of stepping onto a cell or None if the cell can't be
+/// entered, and the heuristic estimates the remaining cost from a point to the goal. the path is
+/// only guaranteed to be the cheapest if the heuristic never overestimates, with a cost of one per
+/// step [`Point::manhattan_distance`] is the right heuristic for [`Connectivity::Four`] and
+/// [`Point::chebyshev_distance`] for [`Connectivity::Eight`]. returns None if start is outside
+/// the container or goal can't be reached
+/// ```
+/// use point_index::*;
+/// let risk = vec![
+///     vec![1, 1, 6],
+///     vec![1, 3, 8],
+///     vec![2, 1, 3],
+/// ];
+/// let cost = |&risk: &u32| Some(risk as usize);
+/// let goal = Point::new(2, 2);
+/// let (path, total) = astar(&risk, Point::ZERO, goal, Connectivity::Four, cost, Point::manhattan_distance).unwrap();
+/// assert_eq!(total, 7);
+/// assert_eq!(path, [Point::new(0, 0), Point::new(0, 1), Point::new(0, 2), Point::new(1, 2), Point::new(2, 2)]);
+///
+/// let open = Grid::new(10, 10, true);
+/// let goal = Point::new(9, 4);
+/// let (path, steps) = astar(&open, Point::ZERO, goal, Connectivity::Eight, |_| Some(1), Point::chebyshev_distance).unwrap();
+/// assert_eq!((path.len(), steps), (10, 9));
+/// ```
+pub fn astar<G, C, H>(
+    grid: &G,
+    start: Point,
+    goal: Point,
+    connectivity: Connectivity,
+    mut cost: C,
+    mut heuristic: H,
+) -> Option<(Vec<Point>, usize)>
+where
+    G: Get<Point> + ?Sized,
+    C: FnMut(&G::Output) -> Option<usize>,
+    H: FnMut(Point, Point) -> usize,
+{
+    grid.get_option(start)?;
+    let mut best: HashMap<Point, (usize, Point)> = HashMap::from([(start, (0, start))]);
+    let mut open = BinaryHeap::from([Reverse((heuristic(start, goal), 0, start))]);
+    while let Some(Reverse((_, so_far, point))) = open.pop() {
+        if point == goal {
+            return Some((walk_back(start, goal, |p| best[&p].1), so_far));
+        }
+        if so_far > best[&point].0 {
+            continue;
+        }
+        for &direction in connectivity.directions() {
+            let next = point + direction;
+            let Some(step) = grid.get_option(next).and_then(&mut cost) else {
+                continue;
+            };
+            let next_cost = so_far.saturating_add(step);
+            if !matches!(best.get(&next), Some(&(known, _)) if known <= next_cost) {
+                best.insert(next, (next_cost, point));
+                open.push(Reverse((next_cost.saturating_add(heuristic(next, goal)), next_cost, next)));
+            }
+        }
+    }
+    None
+}
+
+/// follows the parent links from goal back to start and returns the path from start to goal
+fn walk_back<F: FnMut(Point) -> Point>(start: Point, goal: Point, mut parent: F) -> Vec<Point> {
+    let mut path = vec![goal];
+    let mut point = goal;
+    while point != start {
+        point = parent(point);
+        path.push(point);
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::random_grid;
+
+    #[test]
+    fn bfs_path_matches_distances() {
+        let grid = Grid::from_fn(9, 7, |p| p.x % 4 != 2 || (p.y + p.x / 4) % 6 == 0);
+        let distances = grid.bfs_all_distances(Point::ZERO, |&open| open);
+        for goal in Point::ZERO.to(Point::new(8, 6)) {
+            let path = grid.bfs(Point::ZERO, goal, |&open| open);
+            assert_eq!(path.as_ref().map(|path| path.len() - 1), distances[goal]);
+            if let Some(path) = path {
+                assert!(path.windows(2).all(|w| w[0].manhattan_distance(w[1]) == 1 && grid[w[1]]));
+            }
+        }
+        assert_eq!(grid.bfs(Point::ONE, Point::ONE, |_| false), Some(vec![Point::ONE]));
+        assert_eq!(grid.bfs(Point::new(9, 0), Point::ZERO, |_| true), None);
+    }
+    #[test]
+    fn distance_field_is_the_minimum_over_sources() {
+        let grid = Grid::from_fn(11, 9, |p| (p.x * 5 + p.y * 7) % 6 != 0);
+        let sources = [Point::ZERO, Point::new(10, 8), Point::new(4, 6), Point::new(20, 20)];
+        let field = grid.distance_field(sources, |&open| open);
+        let single: Vec<Grid<Option<usize>>> =
+            sources.iter().map(|&s| grid.bfs_all_distances(s, |&open| open)).collect();
+        for point in Point::ZERO.to(Point::new(10, 8)) {
+            let nearest = single.iter().filter_map(|distances| distances[point]).min();
+            assert_eq!(field[point], nearest.map(|d| d as u32));
+        }
+    }
+    #[test]
+    fn astar_with_unit_costs_matches_bfs() {
+        let grid = Grid::from_fn(12, 9, |p| (p.x * 7 + p.y * 3) % 5 != 0);
+        let start = Point::new(1, 0);
+        let distances = grid.bfs_all_distances(start, |&open| open);
+        let cost = |&open: &bool| open.then_some(1);
+        for goal in Point::ZERO.to(Point::new(11, 8)) {
+            let found = astar(&grid, start, goal, Connectivity::Four, cost, Point::manhattan_distance);
+            assert_eq!(found.as_ref().map(|(_, total)| *total), distances[goal]);
+            if let Some((path, total)) = found {
+                assert_eq!(path.len(), total + 1);
+                assert!(path.windows(2).all(|w| w[0].manhattan_distance(w[1]) == 1 && grid[w[1]]));
+            }
+        }
+    }
+    #[test]
+    fn dijkstra_path_costs_match_cost_field() {
+        let weights = Grid::from_fn(10, 8, |p| (p.x * 31 + p.y * 17) % 9);
+        let cost = |&w: &isize| (w != 0).then_some(w as usize);
+        for connectivity in [Connectivity::Four, Connectivity::Eight] {
+            let costs = weights.dijkstra_costs(Point::new(3, 3), connectivity, cost);
+            for goal in Point::ZERO.to(Point::new(9, 7)) {
+                let found = weights.dijkstra(Point::new(3, 3), goal, connectivity, cost);
+                assert_eq!(found.as_ref().map(|(_, total)| *total), costs[goal]);
+                if let Some((path, total)) = found {
+                    assert_eq!(path[1..].iter().map(|&p| weights[p] as usize).sum::<usize>(), total);
+                }
+            }
+        }
+    }
+    #[test]
+    fn jps_matches_dijkstra_on_random_maps() {
+        for round in 0..60 {
+            let map = random_grid(13, 11, 0x2545_f491 + round, 90 - round / 2);
+            let start = Point::new(round as usize % 13, 0);
+            for connectivity in [Connectivity::Four, Connectivity::Eight] {
+                let costs = map.dijkstra_costs(start, connectivity, |&open| open.then_some(1));
+                for goal in Point::ZERO.to(Point::new(12, 10)) {
+                    let path = map.jps(start, goal, connectivity, |&open| open);
+                    assert_eq!(path.as_ref().map(|path| path.len() - 1), costs[goal]);
+                    if let Some(path) = path {
+                        let steps_ok = path.windows(2).all(|w| match connectivity {
+                            Connectivity::Four => w[0].manhattan_distance(w[1]) == 1,
+                            Connectivity::Eight => w[0].chebyshev_distance(w[1]) == 1,
+                        });
+                        assert!(steps_ok && path[1..].iter().all(|&p| map[p]));
+                    }
+                }
+            }
+        }
+    }
+    #[test]
+    fn theta_star_corners_see_each_other() {
+        let wall = |p: Point| (p.x % 5 == 2 && p.y % 7 != 3) || (p.y == 6 && p.x > 3);
+        let map = Grid::from_fn(16, 12, |p| !wall(p));
+        let start = Point::new(0, 11);
+        let steps = map.dijkstra_costs(start, Connectivity::Eight, |&open| open.then_some(1));
+        for goal in Point::ZERO.to(Point::new(15, 11)) {
+            let found = map.theta_star(start, goal, |&open| open);
+            if let Some((corners, length)) = found {
+                assert!(steps[goal].is_some());
+                assert_eq!((corners[0], corners[corners.len() - 1]), (start, goal));
+                assert!(corners.windows(2).all(|w| w[0].supercover_to(w[1]).all(|p| map[p])));
+                let total: f64 = corners.windows(2).map(|w| (w[0].distance_squared(w[1]) as f64).sqrt()).sum();
+                assert!((total - length).abs() < 1e-9);
+                assert!(length <= steps[goal].unwrap() as f64 * 2f64.sqrt() + 1e-9);
+            }
+        }
+        let open = Grid::new(30, 30, true);
+        let (corners, _) = open.theta_star(Point::ZERO, Point::new(29, 7), |&open| open).unwrap();
+        assert_eq!(corners, [Point::ZERO, Point::new(29, 7)]);
+    }
+}
@@ -0,0 +1,170 @@
+//! a 3d point for working with 3d vectors or arrays, indexed as `[z][y][x]`.
+//!
+//! the directions live in this module so they don't clash with the 2d ones. north and south move
+//! along y the same way [`UP`](crate::UP) and [`DOWN`](crate::DOWN) do in 2d, east and west move
+//! along x and up and down move between layers along z.
+//! ```
+//! use point_index::*;
+//! use point_index::point3::{UP, NORTH, EAST};
+//! let mut voxels = vec![vec![vec![0; 3]; 3]; 3];
+//! let start = Point3::new(1, 1, 0);
+//! voxels[start + UP] = 1;
+//! assert_eq!(voxels[1][1][1], 1);
+//! assert_eq!(voxels.get_option(start + NORTH * 2), None);
+//! assert_eq!(voxels.set(start + EAST, 4), Some(0));
+//! ```
+use std::mem;
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+use crate::{Get, Set};
+
+pub const UP: Point3 = Point3 { x: 0, y: 0, z: 1 };
+pub const DOWN: Point3 = Point3 { x: 0, y: 0, z: -1 };
+pub const NORTH: Point3 = Point3 { x: 0, y: -1, z: 0 };
+pub const SOUTH: Point3 = Point3 { x: 0, y: 1, z: 0 };
+pub const EAST: Point3 = Point3 { x: 1, y: 0, z: 0 };
+pub const WEST: Point3 = Point3 { x: -1, y: 0, z: 0 };
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Point3 {
+    /// creates a new point from three usizes, if you want to create a point from three isize,
+    /// use Point3{x: x, y: y, z: z}
+    /// or Point3::new_isize(x, y, z)
+    pub const fn new(x: usize, y: usize, z: usize) -> Point3 {
+        Point3 {x: x as isize, y: y as isize, z: z as isize}
+    }
+    /// creates a new point from three isize, if you want to create a point from three usize,
+    /// use Point3::new(x, y, z)
+    pub const fn new_isize(x: isize, y: isize, z: isize) -> Point3 {
+        Point3 {x, y, z}
+    }
+
+    fn to_usize_triple(self) -> Option<(usize, usize, usize)> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?, self.z.try_into().ok()?))
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new_isize(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new_isize(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<isize> for Point3 {
+    type Output = Point3;
+
+    fn mul(self, other: isize) -> Point3 {
+        Point3::new_isize(self.x * other, self.y * other, self.z * other)
+    }
+}
+
+impl<A> Index<Point3> for Vec<Vec<Vec<A>>> {
+    type Output = A;
+
+    fn index(&self, index: Point3) -> &A {
+        let (x, y, z) = index.to_usize_triple().unwrap();
+        &self[z][y][x]
+    }
+}
+
+impl<A, const X: usize, const Y: usize, const Z: usize> Index<Point3> for [[[A; X]; Y]; Z] {
+    type Output = A;
+
+    fn index(&self, index: Point3) -> &A {
+        let (x, y, z) = index.to_usize_triple().unwrap();
+        &self[z][y][x]
+    }
+}
+
+impl<A> IndexMut<Point3> for Vec<Vec<Vec<A>>> {
+    fn index_mut(&mut self, index: Point3) -> &mut A {
+        let (x, y, z) = index.to_usize_triple().unwrap();
+        &mut self[z][y][x]
+    }
+}
+
+impl<A, const X: usize, const Y: usize, const Z: usize> IndexMut<Point3> for [[[A; X]; Y]; Z] {
+    fn index_mut(&mut self, index: Point3) -> &mut A {
+        let (x, y, z) = index.to_usize_triple().unwrap();
+        &mut self[z][y][x]
+    }
+}
+
+impl<A> Get<Point3> for Vec<Vec<Vec<A>>> {
+    type Output = A;
+
+    fn get_option(&self, point: Point3) -> Option<&Self::Output> {
+        let (x, y, z) = point.to_usize_triple()?;
+        self.get(z)?.get(y)?.get(x)
+    }
+
+    fn get_mut_option(&mut self, point: Point3) -> Option<&mut Self::Output> {
+        let (x, y, z) = point.to_usize_triple()?;
+        self.get_mut(z)?.get_mut(y)?.get_mut(x)
+    }
+}
+
+impl<A, const X: usize, const Y: usize, const Z: usize> Get<Point3> for [[[A; X]; Y]; Z] {
+    type Output = A;
+
+    fn get_option(&self, point: Point3) -> Option<&Self::Output> {
+        let (x, y, z) = point.to_usize_triple()?;
+        self.get(z)?.get(y)?.get(x)
+    }
+
+    fn get_mut_option(&mut self, point: Point3) -> Option<&mut Self::Output> {
+        let (x, y, z) = point.to_usize_triple()?;
+        self.get_mut(z)?.get_mut(y)?.get_mut(x)
+    }
+}
+
+impl<A> Set<Point3> for Vec<Vec<Vec<A>>> {
+    type Output = A;
+
+    fn set(&mut self, point: Point3, value: Self::Output) -> Option<Self::Output> {
+        Some(mem::replace(self.get_mut_option(point)?, value))
+    }
+}
+
+impl<A, const X: usize, const Y: usize, const Z: usize> Set<Point3> for [[[A; X]; Y]; Z] {
+    type Output = A;
+
+    fn set(&mut self, point: Point3, value: Self::Output) -> Option<Self::Output> {
+        Some(mem::replace(self.get_mut_option(point)?, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indexing_array() {
+        let mut arr = [[[0; 4]; 3]; 2];
+        let point = Point3::new(3, 2, 1);
+        arr[point] = 7;
+        assert_eq!(arr[1][2][3], 7);
+        assert_eq!(arr[point + DOWN], 0);
+        assert_eq!(arr.get_option(point + UP), None);
+    }
+    #[test]
+    fn arithmetic() {
+        let point = Point3::new(1, 1, 1) + EAST * 2 - SOUTH;
+        assert_eq!(point, Point3::new_isize(3, 0, 1));
+    }
+}
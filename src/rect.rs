@@ -0,0 +1,106 @@
+use crate::Point;
+
+/// an axis aligned rectangle of cells, given by its top left corner and its size
+/// ```
+/// use point_index::*;
+/// let rect = Rect::new(Point::new(1, 1), 2, 3);
+/// assert!(rect.contains(Point::new(2, 3)));
+/// assert!(!rect.contains(Point::new(3, 3)));
+/// assert_eq!(rect, Rect::from_corners(Point::new(2, 3), Point::new(1, 1)));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Rect {
+    pub origin: Point,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    pub const fn new(origin: Point, width: usize, height: usize) -> Rect {
+        Rect { origin, width, height }
+    }
+
+    /// the smallest rectangle containing both corners, the corners can be given in any order.
+    /// panics if the corners are isize::MIN and isize::MAX apart, since that width doesn't fit in
+    /// a usize
+    pub fn from_corners(a: Point, b: Point) -> Rect {
+        let span = |from: isize, to: isize| {
+            from.abs_diff(to).checked_add(1).expect("can't make a rect wider than usize::MAX")
+        };
+        Rect::new(a.min(b), span(a.x, b.x), span(a.y, b.y))
+    }
+
+    /// whether the rectangle has no cells
+    pub const fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// the bottom right cell of the rectangle, only meaningful if it isn't empty
+    pub fn bottom_right(&self) -> Point {
+        self.origin + Point::new(self.width, self.height) - Point::ONE
+    }
+
+    /// whether the point is one of the cells of the rectangle
+    pub fn contains(&self, point: Point) -> bool {
+        let offset = point - self.origin;
+        offset.x >= 0 && offset.y >= 0 && (offset.x as usize) < self.width && (offset.y as usize) < self.height
+    }
+
+    /// whether every cell of other is also a cell of self, an empty rect is contained as long as
+    /// its origin is no further out than one past the bottom right corner
+    pub fn contains_rect(&self, other: Rect) -> bool {
+        let offset = other.origin - self.origin;
+        let (Ok(x), Ok(y)) = (usize::try_from(offset.x), usize::try_from(offset.y)) else {
+            return false;
+        };
+        other.width.checked_add(x).is_some_and(|right| right <= self.width)
+            && other.height.checked_add(y).is_some_and(|bottom| bottom <= self.height)
+    }
+
+    /// iterates over every cell of the rectangle in reading order
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let origin = self.origin;
+        let width = self.width;
+        (0..self.width * self.height).map(move |i| origin + Point::from_index(i, width))
+    }
+}
+
+impl Point {
+    /// snaps the point to the nearest cell of the rectangle, panics if the rectangle is empty
+    /// ```
+    /// use point_index::*;
+    /// let rect = Rect::new(Point::new(2, 2), 3, 3);
+    /// assert_eq!(Point::new(0, 3).clamp_to_rect(rect), Point::new(2, 3));
+    /// ```
+    pub fn clamp_to_rect(self, rect: Rect) -> Point {
+        assert!(!rect.is_empty(), "can't clamp a point into an empty rect");
+        self.clamp(rect.origin, rect.bottom_right())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_match_contains() {
+        let rect = Rect::new(Point::new_isize(-2, 1), 4, 2);
+        let points: Vec<Point> = rect.points().collect();
+        assert_eq!(points, rect.origin.to(rect.bottom_right()).collect::<Vec<_>>());
+        let around = Rect::new(rect.origin - Point::ONE, 6, 4);
+        assert_eq!(around.points().filter(|&p| rect.contains(p)).count(), 8);
+        assert!(Rect::new(Point::ZERO, 0, 5).points().next().is_none());
+    }
+
+    #[test]
+    fn corners_at_the_extremes() {
+        let wide = Rect::from_corners(Point::new_isize(isize::MAX, 3), Point::new_isize(isize::MIN + 1, 0));
+        assert_eq!(wide, Rect::new(Point::new_isize(isize::MIN + 1, 0), usize::MAX, 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "wider than usize::MAX")]
+    fn corners_too_far_apart() {
+        Rect::from_corners(Point::new_isize(isize::MIN, 0), Point::new_isize(isize::MAX, 0));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{Get, Grid, Point, ALL_DIRECTIONS, CARDINALS};
+
+/// which cells count as touching, only orthogonal neighbors or diagonal ones as well
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    /// the steps to every neighbor, [`CARDINALS`] or [`ALL_DIRECTIONS`]
+    pub const fn directions(self) -> &'static [Point] {
+        match self {
+            Connectivity::Four => &CARDINALS,
+            Connectivity::Eight => &ALL_DIRECTIONS,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// every point orthogonally connected to start through cells that match the predicate, in the
+    /// order they are reached. empty if start is outside the grid or doesn't match.
+    /// uses an explicit stack so huge regions can't overflow the call stack
+    /// ```
+    /// use point_index::*;
+    /// let map: Grid<char> = "
+    /// ..#.
+    /// .##.
+    /// #...
+    /// ".parse().unwrap();
+    /// assert_eq!(map.flood_region(Point::ZERO, |&c| c == '.').len(), 3);
+    /// assert_eq!(map.flood_region(Point::new(3, 0), |&c| c == '.').len(), 5);
+    /// assert!(map.flood_region(Point::new(2, 0), |&c| c == '.').is_empty());
+    /// ```
+    pub fn flood_region<F: FnMut(&T) -> bool>(&self, start: Point, mut is_fillable: F) -> Vec<Point> {
+        let mut region = Vec::new();
+        if !self.get_option(start).is_some_and(&mut is_fillable) {
+            return region;
+        }
+        let mut seen = Grid::new(self.width, self.height, false);
+        seen[start] = true;
+        let mut stack = vec![start];
+        while let Some(point) = stack.pop() {
+            region.push(point);
+            for (next, cell) in self.neighbors4(point) {
+                if !seen[next] && is_fillable(cell) {
+                    seen[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        region
+    }
+
+    /// sets every cell of the region around start (see [`Grid::flood_region`]) to new_value and
+    /// returns how many cells were changed
+    /// ```
+    /// use point_index::*;
+    /// let mut image: Grid<char> = "
+    /// ..#
+    /// .#.
+    /// #..
+    /// ".parse().unwrap();
+    /// assert_eq!(image.flood_fill(Point::ZERO, |&c| c == '.', 'o'), 3);
+    /// assert_eq!(image, "oo#\no#.\n#..".parse().unwrap());
+    /// ```
+    pub fn flood_fill<F: FnMut(&T) -> bool>(&mut self, start: Point, is_fillable: F, new_value: T) -> usize where T: Clone {
+        let region = self.flood_region(start, is_fillable);
+        for &point in &region {
+            self[point] = new_value.clone();
+        }
+        region.len()
+    }
+
+    /// labels every cell with the region it belongs to, two touching cells are in the same region
+    /// if same_region returns true for them. labels count up from 0 in the reading order of the
+    /// first cell of each region, and the returned vec holds the size of every region
+    /// ```
+    /// use point_index::*;
+    /// let garden: Grid<char> = "
+    /// AAB
+    /// ABA
+    /// CBB
+    /// ".parse().unwrap();
+    /// let (labels, sizes) = garden.connected_components(Connectivity::Four, |a, b| a == b);
+    /// assert_eq!(labels.as_slice(), &[0, 0, 1, 0, 2, 3, 4, 2, 2]);
+    /// assert_eq!(sizes, [3, 1, 3, 1, 1]);
+    /// let (_, sizes) = garden.connected_components(Connectivity::Eight, |a, b| a == b);
+    /// assert_eq!(sizes, [4, 4, 1]);
+    /// ```
+    pub fn connected_components<F: FnMut(&T, &T) -> bool>(
+        &self,
+        connectivity: Connectivity,
+        mut same_region: F,
+    ) -> (Grid<u32>, Vec<usize>) {
+        const UNLABELED: u32 = u32::MAX;
+        let mut labels = Grid::new(self.width, self.height, UNLABELED);
+        let mut sizes = Vec::new();
+        let mut stack = Vec::new();
+        for i in 0..self.data.len() {
+            if labels.data[i] != UNLABELED {
+                continue;
+            }
+            let label = sizes.len() as u32;
+            let start = Point::from_index(i, self.width);
+            labels[start] = label;
+            stack.push(start);
+            let mut size = 0;
+            while let Some(point) = stack.pop() {
+                size += 1;
+                for &direction in connectivity.directions() {
+                    let next = point + direction;
+                    if labels.get_option(next) == Some(&UNLABELED) && same_region(&self[point], &self[next]) {
+                        labels[next] = label;
+                        stack.push(next);
+                    }
+                }
+            }
+            sizes.push(size);
+        }
+        (labels, sizes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_value_can_match_predicate() {
+        let mut grid = Grid::new(300, 300, 0);
+        assert_eq!(grid.flood_fill(Point::new(150, 150), |&n| n < 5, 1), 300 * 300);
+        assert_eq!(grid.flood_fill(Point::new(300, 0), |_| true, 2), 0);
+        let mut region = grid.flood_region(Point::ZERO, |&n| n == 1);
+        region.sort();
+        assert_eq!(region, Point::ZERO.to(Point::new(299, 299)).collect::<Vec<_>>());
+    }
+    #[test]
+    fn components_match_flood_regions() {
+        let grid = Grid::from_fn(7, 5, |p| (p.x * p.x + 3 * p.y) % 4 < 2);
+        let (labels, sizes) = grid.connected_components(Connectivity::Four, |a, b| a == b);
+        assert_eq!(sizes.iter().sum::<usize>(), 35);
+        for point in Point::ZERO.to(Point::new(6, 4)) {
+            let value = grid[point];
+            let region = grid.flood_region(point, |&v| v == value);
+            assert_eq!(region.len(), sizes[labels[point] as usize]);
+            assert!(region.iter().all(|&p| labels[p] == labels[point]));
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{Grid, Point, Rotation};
+
+impl<T: PartialEq> Grid<T> {
+    /// the top left corners of every place where pattern matches the grid, in reading order.
+    /// cells of the pattern that are equal to wildcard match anything, and matches may overlap
+    /// ```
+    /// use point_index::*;
+    /// let sea: Grid<char> = "
+    /// .O..O.
+    /// OOOO.O
+    /// .O..O.
+    /// ".parse().unwrap();
+    /// let monster: Grid<char> = " O \nOOO".parse().unwrap();
+    /// assert_eq!(sea.find_subgrid(&monster, Some(&' ')), [Point::ZERO]);
+    /// assert!(sea.find_subgrid(&monster, None).is_empty());
+    /// ```
+    pub fn find_subgrid(&self, pattern: &Grid<T>, wildcard: Option<&T>) -> Vec<Point> {
+        if pattern.width > self.width || pattern.height > self.height {
+            return Vec::new();
+        }
+        let corners = Point::ZERO.to(Point::new(self.width - pattern.width, self.height - pattern.height));
+        corners.filter(|&corner| self.matches_at(pattern, corner, wildcard)).collect()
+    }
+
+    /// like [`Grid::find_subgrid`] but also looks for every rotation of the pattern and every
+    /// rotation of its mirror image. returns the top left corner of each match together with the
+    /// rotation applied to the pattern and whether it was flipped horizontally before rotating.
+    /// orientations that look the same as an earlier one are skipped so a symmetric pattern isn't
+    /// found twice in the same place
+    /// ```
+    /// use point_index::*;
+    /// let grid: Grid<char> = "
+    /// x..
+    /// xx.
+    /// ...
+    /// ".parse().unwrap();
+    /// let corner: Grid<char> = "xx\n.x".parse().unwrap();
+    /// assert_eq!(grid.find_subgrid_oriented(&corner, None), [(Point::ZERO, Rotation::Half, false)]);
+    /// ```
+    pub fn find_subgrid_oriented(
+        &self,
+        pattern: &Grid<T>,
+        wildcard: Option<&T>,
+    ) -> Vec<(Point, Rotation, bool)>
+    where
+        T: Clone,
+    {
+        let mut orientations: Vec<(Grid<T>, Rotation, bool)> = Vec::new();
+        for flipped in [false, true] {
+            let mut oriented = if flipped { pattern.flip_horizontal() } else { pattern.clone() };
+            for quarter_turns in 0..4 {
+                if !orientations.iter().any(|(seen, _, _)| *seen == oriented) {
+                    orientations.push((oriented.clone(), Rotation::from_quarter_turns(quarter_turns), flipped));
+                }
+                oriented = oriented.rotate_cw();
+            }
+        }
+        let mut found = Vec::new();
+        for (oriented, rotation, flipped) in &orientations {
+            let corners = self.find_subgrid(oriented, wildcard);
+            found.extend(corners.into_iter().map(|corner| (corner, *rotation, *flipped)));
+        }
+        found
+    }
+
+    /// whether pattern matches the grid when its top left corner is at corner, the pattern has to
+    /// fit inside the grid
+    fn matches_at(&self, pattern: &Grid<T>, corner: Point, wildcard: Option<&T>) -> bool {
+        let x = corner.x as usize;
+        pattern.rows().enumerate().all(|(y, pattern_row)| {
+            let row = &self.row(corner.y as usize + y)[x..x + pattern.width];
+            match wildcard {
+                None => row == pattern_row,
+                Some(wildcard) => row.iter().zip(pattern_row).all(|(cell, p)| p == wildcard || p == cell),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Count, Rect};
+
+    #[test]
+    fn matches_agree_with_cell_by_cell_checks() {
+        let grid = Grid::from_fn(11, 9, |p| (p.x * 3 + p.y * p.y) % 4 == 0);
+        let pattern = grid.crop(Rect::new(Point::new(2, 1), 3, 2)).unwrap();
+        let found = grid.find_subgrid(&pattern, None);
+        for corner in Point::ZERO.to(Point::new(8, 7)) {
+            let matches = pattern.enumerate().all(|(p, cell)| grid[corner + p] == *cell);
+            assert_eq!(found.contains(&corner), matches);
+        }
+        assert!(found.contains(&Point::new(2, 1)));
+        let anything = Grid::new(3, 2, true);
+        assert_eq!(grid.find_subgrid(&anything, Some(&true)).len(), 9 * 8);
+        assert!(grid.find_subgrid(&Grid::new(12, 1, true), Some(&true)).is_empty());
+        let dot = Grid::new(1, 1, true);
+        assert_eq!(grid.find_subgrid_oriented(&dot, None).len(), grid.count_eq(&true));
+    }
+}
@@ -0,0 +1,184 @@
+use std::iter;
+use crate::{Direction, Point, Walker, DOWN, DOWN_LEFT, DOWN_RIGHT, LEFT, RIGHT, UP, UP_LEFT, UP_RIGHT};
+
+impl Point {
+    /// iterates over every point in the rectangle with self and other as opposite corners, both
+    /// included, in reading order. the corners can be given in any order
+    /// ```
+    /// use point_index::*;
+    /// let cells: Vec<Point> = Point::new(1, 1).to(Point::new(0, 2)).collect();
+    /// assert_eq!(cells, [Point::new(0, 1), Point::new(1, 1), Point::new(0, 2), Point::new(1, 2)]);
+    /// ```
+    pub fn to(self, other: Point) -> impl Iterator<Item = Point> {
+        let lo = self.min(other);
+        let hi = self.max(other);
+        (lo.y..=hi.y).flat_map(move |y| (lo.x..=hi.x).map(move |x| Point::new_isize(x, y)))
+    }
+
+    /// an endless iterator that spirals outward from self, starting with self, then stepping right
+    /// and turning left (up on the screen) whenever it can. the first `(2r + 1)^2` points are
+    /// exactly the square of chebyshev radius r around self
+    /// ```
+    /// use point_index::*;
+    /// let center = Point::new(5, 5);
+    /// let first: Vec<Point> = center.spiral().take(5).collect();
+    /// assert_eq!(first, [center, center + RIGHT, center + UP_RIGHT, center + UP, center + UP_LEFT]);
+    /// // find the nearest cell holding a 1
+    /// let grid = vec![vec![0, 0, 1], vec![0, 0, 0], vec![0, 0, 0]];
+    /// let nearest = Point::new(0, 2).spiral().find(|&p| grid.get_option(p) == Some(&1));
+    /// assert_eq!(nearest, Some(Point::new(2, 0)));
+    /// ```
+    pub fn spiral(self) -> impl Iterator<Item = Point> {
+        let mut walker = Walker::new(self, Direction::Right);
+        let mut leg_len = 1;
+        let mut leg_progress = 0;
+        let mut legs_at_len = 0;
+        iter::once(self).chain(iter::from_fn(move || {
+            walker.forward(1);
+            leg_progress += 1;
+            if leg_progress == leg_len {
+                leg_progress = 0;
+                walker.turn_left();
+                legs_at_len += 1;
+                if legs_at_len == 2 {
+                    legs_at_len = 0;
+                    leg_len += 1;
+                }
+            }
+            Some(walker.pos)
+        }))
+    }
+
+    /// the spiral from self cut off once it has covered the square of chebyshev radius `radius`
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::ZERO.spiral_within(2).count(), 25);
+    /// assert!(Point::ZERO.spiral_within(2).all(|p| p.chebyshev_distance(Point::ZERO) <= 2));
+    /// ```
+    pub fn spiral_within(self, radius: usize) -> impl Iterator<Item = Point> {
+        self.spiral().take((2 * radius + 1) * (2 * radius + 1))
+    }
+
+    /// iterates over the square ring of points at exactly chebyshev distance `radius` from self,
+    /// going clockwise from the top left corner. a radius of 0 yields only self
+    /// ```
+    /// use point_index::*;
+    /// let ring: Vec<Point> = Point::new(1, 1).ring(1).collect();
+    /// assert_eq!(ring, [
+    ///     Point::new(0, 0), Point::new(1, 0), Point::new(2, 0), Point::new(2, 1),
+    ///     Point::new(2, 2), Point::new(1, 2), Point::new(0, 2), Point::new(0, 1),
+    /// ]);
+    /// ```
+    pub fn ring(self, radius: usize) -> impl Iterator<Item = Point> {
+        let corner = self + UP_LEFT * radius as isize;
+        iter::once(self).filter(move |_| radius == 0).chain(outline(corner, [RIGHT, DOWN, LEFT, UP], 2 * radius))
+    }
+
+    /// iterates over the diamond of points at exactly manhattan distance `radius` from self, going
+    /// clockwise from the top. a radius of 0 yields only self
+    /// ```
+    /// use point_index::*;
+    /// let diamond: Vec<Point> = Point::new(2, 2).manhattan_ring(1).collect();
+    /// assert_eq!(diamond, [Point::new(2, 1), Point::new(3, 2), Point::new(2, 3), Point::new(1, 2)]);
+    /// assert_eq!(Point::ZERO.manhattan_ring(3).count(), 12);
+    /// ```
+    pub fn manhattan_ring(self, radius: usize) -> impl Iterator<Item = Point> {
+        let top = self + UP * radius as isize;
+        let sides = [DOWN_RIGHT, DOWN_LEFT, UP_LEFT, UP_RIGHT];
+        iter::once(self).filter(move |_| radius == 0).chain(outline(top, sides, radius))
+    }
+
+    /// iterates over every point within manhattan distance `radius` of self, in reading order
+    /// ```
+    /// use point_index::*;
+    /// let blast: Vec<Point> = Point::new(1, 1).points_within_manhattan(1).collect();
+    /// assert_eq!(blast, [
+    ///     Point::new(1, 0), Point::new(0, 1), Point::new(1, 1), Point::new(2, 1), Point::new(1, 2),
+    /// ]);
+    /// ```
+    pub fn points_within_manhattan(self, radius: usize) -> impl Iterator<Item = Point> {
+        let r = radius as isize;
+        (-r..=r).flat_map(move |dy| {
+            let half_width = r - dy.abs();
+            (-half_width..=half_width).map(move |dx| self + Point::new_isize(dx, dy))
+        })
+    }
+
+    /// iterates over every point within chebyshev distance `radius` of self, the square around it,
+    /// in reading order
+    /// ```
+    /// use point_index::*;
+    /// assert_eq!(Point::ZERO.points_within_chebyshev(1).collect::<Vec<_>>()[4], Point::ZERO);
+    /// assert_eq!(Point::ZERO.points_within_chebyshev(2).count(), 25);
+    /// ```
+    pub fn points_within_chebyshev(self, radius: usize) -> impl Iterator<Item = Point> {
+        let offset = Point::ONE * radius as isize;
+        (self - offset).to(self + offset)
+    }
+}
+
+/// walks the closed outline that starts at start and follows each direction for side steps,
+/// yielding every point once
+fn outline(start: Point, directions: [Point; 4], side: usize) -> impl Iterator<Item = Point> {
+    directions.into_iter().scan(start, move |corner, direction| {
+        let from = *corner;
+        *corner = from + direction * side as isize;
+        Some((0..side).map(move |i| from + direction * i as isize))
+    }).flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rectangle_covers_grid() {
+        let mut grid = [[0; 4]; 3];
+        for point in Point::new(3, 2).to(Point::ZERO) {
+            grid[point] += 1;
+        }
+        assert_eq!(grid, [[1; 4]; 3]);
+        assert_eq!(Point::ONE.to(Point::ONE).count(), 1);
+    }
+    #[test]
+    fn spiral_covers_square_once() {
+        let mut seen: Vec<Point> = Point::new(2, 2).spiral_within(3).collect();
+        let expected: Vec<Point> = Point::new_isize(-1, -1).to(Point::new(5, 5)).collect();
+        assert!(seen.windows(2).all(|w| w[0].manhattan_distance(w[1]) == 1));
+        seen.sort();
+        assert_eq!(seen, expected);
+    }
+    #[test]
+    fn rings_layer_the_spiral() {
+        let center = Point::new_isize(-4, 9);
+        assert_eq!(center.ring(0).collect::<Vec<_>>(), [center]);
+        let mut layered: Vec<Point> = (0..4).flat_map(|r| center.ring(r)).collect();
+        let mut spiral: Vec<Point> = center.spiral_within(3).collect();
+        layered.sort();
+        spiral.sort();
+        assert_eq!(layered, spiral);
+        assert!(center.ring(5).all(|p| p.chebyshev_distance(center) == 5));
+    }
+    #[test]
+    fn disks_match_distance_filters() {
+        let center = Point::new_isize(-2, 5);
+        let square: Vec<Point> = center.points_within_chebyshev(4).collect();
+        let diamond: Vec<Point> = center.points_within_manhattan(4).collect();
+        let filtered: Vec<Point> = square.iter().copied().filter(|p| p.manhattan_distance(center) <= 4).collect();
+        assert_eq!(diamond, filtered);
+        assert!(square.windows(2).all(|w| w[0] < w[1]));
+    }
+    #[test]
+    fn manhattan_ring_is_exact() {
+        let center = Point::new(3, 1);
+        for radius in 0..6 {
+            let mut diamond: Vec<Point> = center.manhattan_ring(radius).collect();
+            let mut expected: Vec<Point> = center.spiral_within(radius)
+                .filter(|p| p.manhattan_distance(center) == radius)
+                .collect();
+            diamond.sort();
+            expected.sort();
+            assert_eq!(diamond, expected);
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{Get, Grid, Point, DOWN, LEFT, RIGHT, UP};
+
+/// what a ray does when it reaches a cell, see [`Grid::raycast`]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RayStep {
+    /// the ray stops at this cell
+    Hit,
+    /// the ray continues past this cell
+    Pass,
+}
+
+impl<T> Grid<T> {
+    /// steps from start in direction, asking f about every cell it reaches, until f answers hit or
+    /// the ray leaves the grid. returns the cell that was hit, None if the ray left the grid, and
+    /// every cell passed through before it. start itself isn't checked. panics if direction is zero
+    /// ```
+    /// use point_index::*;
+    /// let forest = Grid::parse_with("30373\n25512\n65332", |c| c.to_digit(10)).unwrap();
+    /// let tree = Point::new(2, 1);
+    /// let taller = |&h: &u32| if h >= 5 { RayStep::Hit } else { RayStep::Pass };
+    /// let (blocker, passed) = forest.raycast(tree, LEFT, taller);
+    /// assert_eq!((blocker, passed.len()), (Some(Point::new(1, 1)), 0));
+    /// let (blocker, passed) = forest.raycast(tree, RIGHT, taller);
+    /// assert_eq!(blocker, None);
+    /// assert_eq!(passed, [Point::new(3, 1), Point::new(4, 1)]);
+    /// ```
+    pub fn raycast<F>(&self, start: Point, direction: Point, mut f: F) -> (Option<Point>, Vec<Point>)
+    where
+        F: FnMut(&T) -> RayStep,
+    {
+        assert_ne!(direction, Point::ZERO, "a ray needs a direction");
+        let mut passed = Vec::new();
+        let mut point = start + direction;
+        while let Some(cell) = self.get_option(point) {
+            if f(cell) == RayStep::Hit {
+                return (Some(point), passed);
+            }
+            passed.push(point);
+            point += direction;
+        }
+        (None, passed)
+    }
+}
+
+impl<T> Grid<T> {
+    /// every cell that can be seen from origin within the given euclidean radius, using symmetric
+    /// shadowcasting. a cell that blocks sight is visible itself but hides what is behind it, and
+    /// two floor cells can always see each other if either can see the other. cells outside the
+    /// grid block sight. returns a grid with nothing visible if origin is outside the grid
+    /// ```
+    /// use point_index::*;
+    /// let dungeon: Grid<char> = "
+    /// .......
+    /// ...#...
+    /// .......
+    /// ...@...
+    /// ".parse().unwrap();
+    /// let player = dungeon.find(&'@').unwrap();
+    /// let seen = dungeon.visible_from(player, 10, |&c| c == '#');
+    /// assert!(seen[Point::new(3, 1)]);
+    /// assert!(!seen[Point::new(3, 0)]);
+    /// assert!(seen[Point::new(0, 0)]);
+    /// assert!(!dungeon.visible_from(player, 1, |&c| c == '#')[Point::new(3, 1)]);
+    /// ```
+    pub fn visible_from<F>(&self, origin: Point, radius: usize, mut blocks_sight: F) -> Grid<bool>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut visible = Grid::new(self.width, self.height, false);
+        if !self.in_bounds(origin) {
+            return visible;
+        }
+        visible[origin] = true;
+        let radius = radius.min(self.width + self.height);
+        let mut is_wall = |point: Point| match self.get_option(point) {
+            Some(cell) => blocks_sight(cell),
+            None => true,
+        };
+        for (forward, sideways) in [(UP, RIGHT), (DOWN, RIGHT), (RIGHT, DOWN), (LEFT, DOWN)] {
+            let mut rows = vec![Row { depth: 1, start: (-1, 1), end: (1, 1) }];
+            while let Some(mut row) = rows.pop() {
+                if row.depth as usize > radius {
+                    continue;
+                }
+                let mut previous_wall = None;
+                for column in row.min_column()..=row.max_column() {
+                    let point = origin + forward * row.depth + sideways * column;
+                    let wall = is_wall(point);
+                    if (wall || row.is_symmetric(column)) && point.distance_squared(origin) <= radius * radius {
+                        if let Some(cell) = visible.get_mut_option(point) {
+                            *cell = true;
+                        }
+                    }
+                    if previous_wall == Some(true) && !wall {
+                        row.start = slope(row.depth, column);
+                    }
+                    if previous_wall == Some(false) && wall {
+                        rows.push(Row { depth: row.depth + 1, start: row.start, end: slope(row.depth, column) });
+                    }
+                    previous_wall = Some(wall);
+                }
+                if previous_wall == Some(false) {
+                    rows.push(Row { depth: row.depth + 1, ..row });
+                }
+            }
+        }
+        visible
+    }
+}
+
+/// a row of one quadrant of the shadowcast, columns between the start and end slopes are lit.
+/// slopes are fractions stored as (numerator, denominator) with a positive denominator
+#[derive(Debug, Clone, Copy)]
+struct Row {
+    depth: isize,
+    start: (isize, isize),
+    end: (isize, isize),
+}
+
+impl Row {
+    /// the first column, `depth * start` rounded with ties going up
+    fn min_column(&self) -> isize {
+        let (num, den) = self.start;
+        (2 * self.depth * num + den).div_euclid(2 * den)
+    }
+
+    /// the last column, `depth * end` rounded with ties going down
+    fn max_column(&self) -> isize {
+        let (num, den) = self.end;
+        -(den - 2 * self.depth * num).div_euclid(2 * den)
+    }
+
+    /// whether the center of the column is inside the lit part of the row, which is what makes
+    /// floor cells see each other symmetrically
+    fn is_symmetric(&self, column: isize) -> bool {
+        column * self.start.1 >= self.depth * self.start.0 && column * self.end.1 <= self.depth * self.end.0
+    }
+}
+
+/// the slope through the left edge of a cell
+fn slope(depth: isize, column: isize) -> (isize, isize) {
+    (2 * column - 1, 2 * depth)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ALL_DIRECTIONS;
+    use crate::grid::random_grid;
+
+    #[test]
+    fn rays_stop_at_walls_or_edges() {
+        let grid = Grid::from_fn(9, 9, |p| p.x == 0 || p.y == 8);
+        let center = Point::new(4, 4);
+        for direction in ALL_DIRECTIONS {
+            let (hit, passed) = grid.raycast(center, direction, |&wall| {
+                if wall { RayStep::Hit } else { RayStep::Pass }
+            });
+            assert_eq!(hit.is_some(), direction.x < 0 || direction.y > 0);
+            assert_eq!(passed.len(), if hit.is_some() { 3 } else { 4 });
+            assert!(passed.iter().all(|&p| (p - center).signum() == direction));
+        }
+        let (hit, passed) = grid.raycast(Point::new(10, 9), Point::new_isize(-2, -1), |_| RayStep::Pass);
+        assert_eq!((hit, passed.len()), (None, 5));
+    }
+    #[test]
+    fn shadowcasting_is_symmetric() {
+        let map = random_grid(15, 12, 7, 25);
+        let floor: Vec<Point> = map.find_all(|&wall| !wall).collect();
+        let fields: Vec<Grid<bool>> = floor.iter().map(|&p| map.visible_from(p, 100, |&wall| wall)).collect();
+        for (a, seen_from_a) in floor.iter().zip(&fields) {
+            for (b, seen_from_b) in floor.iter().zip(&fields) {
+                assert_eq!(seen_from_a[*b], seen_from_b[*a]);
+            }
+        }
+        let open = Grid::new(21, 21, false);
+        let seen = open.visible_from(Point::new(10, 10), 6, |&wall| wall);
+        for point in Point::ZERO.to(Point::new(20, 20)) {
+            assert_eq!(seen[point], point.distance_squared(Point::new(10, 10)) <= 36);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+use crate::{Get, Point, Rect, Set};
+
+/// an unbounded grid that only stores the cells that were written to, every other cell reads as
+/// the default value. useful when the interesting area grows without a known limit
+/// ```
+/// use point_index::*;
+/// let mut sand = SparseGrid::new('.');
+/// sand[Point::new(500, 0)] = '+';
+/// sand[Point::new_isize(-3, 9)] = '#';
+/// assert_eq!(sand[Point::new(500, 0)], '+');
+/// assert_eq!(sand[Point::new(7, 7)], '.');
+/// assert_eq!(sand.len(), 2);
+/// assert_eq!(sand.bounding_box(), Some(Rect::new(Point::new_isize(-3, 0), 504, 10)));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SparseGrid<T> {
+    pub(crate) cells: HashMap<Point, T>,
+    default: T,
+}
+
+impl<T> SparseGrid<T> {
+    /// creates an empty grid where every cell reads as default
+    pub fn new(default: T) -> SparseGrid<T> {
+        SparseGrid { cells: HashMap::new(), default }
+    }
+
+    /// the value of every cell that was never written
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// the number of stored cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// whether no cell is stored
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// whether a value is stored for the point, even if it's equal to the default
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// forgets the value stored for the point so it reads as the default again, returning it
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    /// iterates over the stored cells in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    /// iterates mutably over the stored cells in no particular order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.cells.iter_mut().map(|(&point, value)| (point, value))
+    }
+
+    /// the smallest rectangle containing every stored cell, None if there are none
+    /// ```
+    /// use point_index::*;
+    /// let mut ant = SparseGrid::new(false);
+    /// assert_eq!(ant.bounding_box(), None);
+    /// ant[Point::new(2, 3)] = true;
+    /// assert_eq!(ant.bounding_box(), Some(Rect::new(Point::new(2, 3), 1, 1)));
+    /// ```
+    pub fn bounding_box(&self) -> Option<Rect> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+        let (min, max) = points.fold((first, first), |(min, max), &p| (min.min(p), max.max(p)));
+        Some(Rect::from_corners(min, max))
+    }
+}
+
+/// reads the stored value or the default
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, index: Point) -> &T {
+        self.cells.get(&index).unwrap_or(&self.default)
+    }
+}
+
+/// stores a copy of the default first if the cell wasn't written yet
+impl<T: Clone> IndexMut<Point> for SparseGrid<T> {
+    fn index_mut(&mut self, index: Point) -> &mut T {
+        self.cells.entry(index).or_insert_with(|| self.default.clone())
+    }
+}
+
+/// every point is inside a sparse grid so this always returns Some, reading the default for
+/// missing cells and storing a copy of it before handing out a mutable reference
+impl<T: Clone> Get<Point> for SparseGrid<T> {
+    type Output = T;
+
+    fn get_option(&self, point: Point) -> Option<&T> {
+        Some(&self[point])
+    }
+
+    fn get_mut_option(&mut self, point: Point) -> Option<&mut T> {
+        Some(&mut self[point])
+    }
+}
+
+/// always stores the value, returning what was there before or the default
+impl<T: Clone> Set<Point> for SparseGrid<T> {
+    type Output = T;
+
+    fn set(&mut self, point: Point, value: T) -> Option<T> {
+        Some(self.cells.insert(point, value).unwrap_or_else(|| self.default.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_default_until_written() {
+        let mut grid = SparseGrid::new(0);
+        let far = Point::new_isize(-1_000_000, 1_000_000);
+        assert_eq!(grid.get_option(far), Some(&0));
+        assert!(!grid.contains(far));
+        assert_eq!(grid.set(far, 4), Some(0));
+        assert_eq!(grid.set(far, 5), Some(4));
+        *grid.get_mut_option(Point::ZERO).unwrap() += 1;
+        let mut stored: Vec<(Point, i32)> = grid.iter().map(|(p, &v)| (p, v)).collect();
+        stored.sort();
+        assert_eq!(stored, [(Point::ZERO, 1), (far, 5)]);
+        assert_eq!(grid.remove(far), Some(5));
+        assert_eq!(grid[far], 0);
+    }
+}
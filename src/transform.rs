@@ -0,0 +1,188 @@
+use std::ops::Mul;
+use crate::{Direction, Point};
+
+/// a rotation by a multiple of 90 degrees, so a transformation can be stored and passed around as
+/// data instead of only being a method call
+/// ```
+/// use point_index::*;
+/// let turns = [Rotation::Clockwise, Rotation::Half, Rotation::Clockwise];
+/// let total = turns.into_iter().fold(Rotation::None, |acc, turn| acc * turn);
+/// assert_eq!(total, Rotation::None);
+/// assert_eq!(Rotation::Clockwise.apply(UP), RIGHT);
+/// assert_eq!(Rotation::CounterClockwise.apply(Direction::Up), Direction::Left);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rotation {
+    None,
+    Clockwise,
+    Half,
+    CounterClockwise,
+}
+
+impl Rotation {
+    /// the rotation made of a number of clockwise quarter turns, negative turns go counter clockwise
+    pub const fn from_quarter_turns(quarter_turns: i32) -> Rotation {
+        match quarter_turns.rem_euclid(4) {
+            0 => Rotation::None,
+            1 => Rotation::Clockwise,
+            2 => Rotation::Half,
+            _ => Rotation::CounterClockwise,
+        }
+    }
+
+    /// the number of clockwise quarter turns this rotation makes, between 0 and 3
+    pub const fn quarter_turns(self) -> i32 {
+        self as i32
+    }
+
+    /// the rotation that undoes this one
+    pub const fn inverse(self) -> Rotation {
+        Rotation::from_quarter_turns(-self.quarter_turns())
+    }
+
+    /// the rotation that applies other first and then self, the same as `self * other`
+    pub const fn compose(self, other: Rotation) -> Rotation {
+        Rotation::from_quarter_turns(self.quarter_turns() + other.quarter_turns())
+    }
+
+    /// rotates anything that implements Rotate, like a point or a direction
+    pub fn apply<T: Rotate>(self, value: T) -> T {
+        value.rotate(self)
+    }
+}
+
+/// combines two rotations, applying the result is the same as applying both
+impl Mul for Rotation {
+    type Output = Rotation;
+
+    fn mul(self, other: Rotation) -> Rotation {
+        self.compose(other)
+    }
+}
+
+/// something that can be turned by a [`Rotation`]
+pub trait Rotate {
+    fn rotate(self, rotation: Rotation) -> Self;
+}
+
+/// rotates the point around the origin
+impl Rotate for Point {
+    fn rotate(self, rotation: Rotation) -> Point {
+        match rotation {
+            Rotation::None => self,
+            Rotation::Clockwise => self.rotate_cw(),
+            Rotation::Half => -self,
+            Rotation::CounterClockwise => self.rotate_ccw(),
+        }
+    }
+}
+
+impl Rotate for Direction {
+    fn rotate(self, rotation: Rotation) -> Direction {
+        match rotation {
+            Rotation::None => self,
+            Rotation::Clockwise => self.turn_right(),
+            Rotation::Half => self.opposite(),
+            Rotation::CounterClockwise => self.turn_left(),
+        }
+    }
+}
+
+/// a translation plus a [`Rotation`], for moving points from a local frame (like a robot's) into
+/// the world frame using only integer math. the rotation is applied first. storing a rotation
+/// rather than a count of quarter turns means equal poses always compare and hash equal
+/// ```
+/// use point_index::*;
+/// // a robot at (5, 5) facing right, which is one clockwise turn from up
+/// let robot = Pose::new(Point::new(5, 5), 1);
+/// // something two cells in front of the robot and one to its left
+/// let reading = UP * 2 + LEFT;
+/// assert_eq!(robot.apply(reading), Point::new(7, 4));
+/// assert_eq!(robot.inverse().apply(Point::new(7, 4)), reading);
+/// assert_eq!(robot, Pose::new(Point::new(5, 5), -3));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Pose {
+    pub translation: Point,
+    pub rotation: Rotation,
+}
+
+impl Pose {
+    /// the pose that leaves every point where it is
+    pub const IDENTITY: Pose = Pose { translation: Point::ZERO, rotation: Rotation::None };
+
+    /// a pose from a translation and a number of clockwise quarter turns, negative turns go
+    /// counter clockwise
+    pub const fn new(translation: Point, quarter_turns: i32) -> Pose {
+        Pose { translation, rotation: Rotation::from_quarter_turns(quarter_turns) }
+    }
+
+    /// moves a point from the local frame of the pose into the outer frame
+    pub fn apply(self, point: Point) -> Point {
+        self.translation + self.rotation.apply(point)
+    }
+
+    /// the pose that undoes this one, `pose.inverse().apply(pose.apply(p)) == p`
+    pub fn inverse(self) -> Pose {
+        let rotation = self.rotation.inverse();
+        Pose { translation: -rotation.apply(self.translation), rotation }
+    }
+
+    /// the pose that applies other first and then self, the same as `self * other`
+    pub fn compose(self, other: Pose) -> Pose {
+        Pose { translation: self.apply(other.translation), rotation: self.rotation * other.rotation }
+    }
+}
+
+/// composes two poses, `(a * b).apply(p)` is the same as `a.apply(b.apply(p))`
+/// ```
+/// use point_index::*;
+/// let world_from_robot = Pose::new(Point::new(10, 0), 2);
+/// let robot_from_sensor = Pose::new(Point::new(0, 1), -1);
+/// let world_from_sensor = world_from_robot * robot_from_sensor;
+/// let reading = Point::new(3, 0);
+/// assert_eq!(world_from_sensor.apply(reading), world_from_robot.apply(robot_from_sensor.apply(reading)));
+/// ```
+impl Mul for Pose {
+    type Output = Pose;
+
+    fn mul(self, other: Pose) -> Pose {
+        self.compose(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotation_composition() {
+        let all = [Rotation::None, Rotation::Clockwise, Rotation::Half, Rotation::CounterClockwise];
+        let point = Point::new(2, 1);
+        for a in all {
+            assert_eq!(a * a.inverse(), Rotation::None);
+            assert_eq!(a.apply(point), point.rotate_around(Point::ZERO, a.quarter_turns()));
+            for b in all {
+                assert_eq!((a * b).apply(point), a.apply(b.apply(point)));
+                assert_eq!((a * b).apply(Direction::Left), a.apply(b.apply(Direction::Left)));
+            }
+        }
+    }
+    #[test]
+    fn composition_and_inverse() {
+        let poses = [
+            Pose::new(Point::new(1, 2), 1),
+            Pose::new(Point::new_isize(-4, 0), 3),
+            Pose::new(Point::new(0, 7), -6),
+        ];
+        let point = Point::new_isize(3, -5);
+        for a in poses {
+            assert_eq!((a * a.inverse()).apply(point), point);
+            assert_eq!(a * a.inverse(), Pose::IDENTITY);
+            for b in poses {
+                assert_eq!((a * b).apply(point), a.apply(b.apply(point)));
+            }
+        }
+    }
+}
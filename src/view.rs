@@ -0,0 +1,248 @@
+use std::ops::{Index, IndexMut};
+use num_traits::PrimInt;
+use crate::{GenericPoint, Get, Grid, Point, Rect};
+
+impl<T> Grid<T> {
+    /// a borrowed window into the grid, points used to index the view are relative to the top
+    /// left corner of rect. returns None if rect doesn't fit inside the grid
+    /// ```
+    /// use point_index::*;
+    /// let grid = Grid::from_fn(4, 4, |p| p.to_index(4).unwrap());
+    /// let view = grid.view(Rect::new(Point::new(1, 2), 2, 2)).unwrap();
+    /// assert_eq!(view[Point::new(0, 0)], 9);
+    /// assert_eq!(view.get_option(Point::new(2, 0)), None);
+    /// ```
+    pub fn view(&self, rect: Rect) -> Option<GridView<'_, T>> {
+        if !self.fits(rect) {
+            return None;
+        }
+        Some(GridView { grid: self, rect })
+    }
+
+    /// a mutable window into the grid, points used to index the view are relative to the top
+    /// left corner of rect. returns None if rect doesn't fit inside the grid
+    /// ```
+    /// use point_index::*;
+    /// let mut grid = Grid::new(4, 3, '.');
+    /// let mut view = grid.view_mut(Rect::new(Point::new(1, 1), 2, 2)).unwrap();
+    /// view.fill('#');
+    /// view[Point::new(1, 1)] = '@';
+    /// assert_eq!(grid, "....\n.##.\n.#@.".parse().unwrap());
+    /// ```
+    pub fn view_mut(&mut self, rect: Rect) -> Option<GridViewMut<'_, T>> {
+        if !self.fits(rect) {
+            return None;
+        }
+        Some(GridViewMut { grid: self, rect })
+    }
+
+    /// every window of the given size that fits inside the grid, with the position of its top left
+    /// corner, in reading order. panics if either dimension of size isn't positive
+    /// ```
+    /// use point_index::*;
+    /// let grid = Grid::from_fn(4, 3, |p| p.x * p.y);
+    /// let (best, _) = grid.windows(Point::new(2, 2))
+    ///     .max_by_key(|(_, window)| window.iter().sum::<isize>())
+    ///     .unwrap();
+    /// assert_eq!(best, Point::new(2, 1));
+    /// assert_eq!(grid.windows(Point::new(3, 3)).count(), 2);
+    /// assert_eq!(grid.windows(Point::new(5, 1)).count(), 0);
+    /// ```
+    pub fn windows(&self, size: Point) -> impl Iterator<Item = (Point, GridView<'_, T>)> {
+        assert!(size.x > 0 && size.y > 0, "window size must be positive");
+        let (width, height) = (size.x as usize, size.y as usize);
+        let positions = Rect::new(
+            Point::ZERO,
+            (self.width + 1).saturating_sub(width),
+            (self.height + 1).saturating_sub(height),
+        );
+        positions.points().map(move |origin| (origin, GridView { grid: self, rect: Rect::new(origin, width, height) }))
+    }
+}
+
+/// a read only window into a [`Grid`] with its own coordinate frame, created by [`Grid::view`]
+#[derive(Debug, Clone, Copy)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    rect: Rect,
+}
+
+impl<'a, T> GridView<'a, T> {
+    /// the part of the underlying grid this view covers
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// a narrower view into the same grid, rect is relative to this view
+    pub fn view(&self, rect: Rect) -> Option<GridView<'a, T>> {
+        if !self.local_rect().contains_rect(rect) {
+            return None;
+        }
+        let inner = Rect::new(self.rect.origin + rect.origin, rect.width, rect.height);
+        Some(GridView { grid: self.grid, rect: inner })
+    }
+
+    /// the cell at a point relative to the view, None if the point is outside the view
+    pub fn get_option<P: PrimInt>(&self, point: GenericPoint<P>) -> Option<&'a T> {
+        self.grid.get_option(self.translate(point)?)
+    }
+
+    /// the rows of the view as slices into the underlying grid
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let Rect { origin, width, .. } = self.rect;
+        let (x, y) = (origin.x as usize, origin.y as usize);
+        self.grid.rows().skip(y).take(self.rect.height).map(move |row| &row[x..x + width])
+    }
+
+    /// iterates over the cells of the view in reading order
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.rows().flatten()
+    }
+
+    /// copies the cells of the view into a new grid
+    pub fn to_grid(&self) -> Grid<T> where T: Clone {
+        Grid::from_fn(self.rect.width, self.rect.height, |p| self[p].clone())
+    }
+
+    fn local_rect(&self) -> Rect {
+        Rect::new(Point::ZERO, self.rect.width, self.rect.height)
+    }
+
+    fn translate<P: PrimInt>(&self, point: GenericPoint<P>) -> Option<Point> {
+        let point = point.cast()?;
+        self.local_rect().contains(point).then(|| point + self.rect.origin)
+    }
+}
+
+impl<T, P: PrimInt> Index<GenericPoint<P>> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: GenericPoint<P>) -> &T {
+        &self.grid[self.translate(index).expect("point is outside the view")]
+    }
+}
+
+/// a mutable window into a [`Grid`] with its own coordinate frame, created by [`Grid::view_mut`]
+#[derive(Debug)]
+pub struct GridViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    rect: Rect,
+}
+
+impl<T> GridViewMut<'_, T> {
+    /// the part of the underlying grid this view covers
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// a read only view of the same cells
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView { grid: self.grid, rect: self.rect }
+    }
+
+    /// a narrower mutable view into the same grid, rect is relative to this view
+    pub fn view_mut(&mut self, rect: Rect) -> Option<GridViewMut<'_, T>> {
+        if !self.as_view().local_rect().contains_rect(rect) {
+            return None;
+        }
+        let inner = Rect::new(self.rect.origin + rect.origin, rect.width, rect.height);
+        Some(GridViewMut { grid: self.grid, rect: inner })
+    }
+
+    /// the cell at a point relative to the view, None if the point is outside the view
+    pub fn get_option<P: PrimInt>(&self, point: GenericPoint<P>) -> Option<&T> {
+        self.grid.get_option(self.as_view().translate(point)?)
+    }
+
+    /// the cell at a point relative to the view, None if the point is outside the view
+    pub fn get_mut_option<P: PrimInt>(&mut self, point: GenericPoint<P>) -> Option<&mut T> {
+        let point = self.as_view().translate(point)?;
+        self.grid.get_mut_option(point)
+    }
+
+    /// the rows of the view as slices into the underlying grid
+    fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let Rect { origin, width, .. } = self.rect;
+        let (x, y) = (origin.x as usize, origin.y as usize);
+        self.grid.rows_mut().skip(y).take(self.rect.height).map(move |row| &mut row[x..x + width])
+    }
+
+    /// sets every cell of the view to value
+    pub fn fill(&mut self, value: T) where T: Clone {
+        for row in self.rows_mut() {
+            row.fill(value.clone());
+        }
+    }
+
+    /// copies every cell of source into the view, panics if source doesn't have the same size
+    /// as the view
+    /// ```
+    /// use point_index::*;
+    /// let stamp: Grid<char> = "ab\ncd".parse().unwrap();
+    /// let mut canvas = Grid::new(3, 3, '.');
+    /// canvas.view_mut(Rect::new(Point::new(1, 0), 2, 2)).unwrap().copy_from(&stamp);
+    /// assert_eq!(canvas, ".ab\n.cd\n...".parse().unwrap());
+    /// ```
+    pub fn copy_from(&mut self, source: &Grid<T>) where T: Clone {
+        assert!(
+            source.width == self.rect.width && source.height == self.rect.height,
+            "source grid and view have different sizes"
+        );
+        for (row, source_row) in self.rows_mut().zip(source.rows()) {
+            row.clone_from_slice(source_row);
+        }
+    }
+}
+
+impl<T, P: PrimInt> Index<GenericPoint<P>> for GridViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: GenericPoint<P>) -> &T {
+        self.get_option(index).expect("point is outside the view")
+    }
+}
+
+impl<T, P: PrimInt> IndexMut<GenericPoint<P>> for GridViewMut<'_, T> {
+    fn index_mut(&mut self, index: GenericPoint<P>) -> &mut T {
+        self.get_mut_option(index).expect("point is outside the view")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_views() {
+        let grid = Grid::from_fn(5, 5, |p| p);
+        let outer = grid.view(Rect::new(Point::new(1, 1), 3, 3)).unwrap();
+        let inner = outer.view(Rect::new(Point::new(1, 1), 2, 2)).unwrap();
+        assert_eq!(inner[Point::new(1, 1)], Point::new(3, 3));
+        assert!(outer.view(Rect::new(Point::new(2, 2), 2, 2)).is_none());
+        assert_eq!(inner.to_grid(), grid.crop(Rect::new(Point::new(2, 2), 2, 2)).unwrap());
+        assert_eq!(outer.get_option(Point::new_isize(-1, 0)), None);
+    }
+    #[test]
+    fn windows_cover_every_position() {
+        let grid = Grid::from_fn(5, 4, |p| p);
+        let windows: Vec<(Point, GridView<Point>)> = grid.windows(Point::new(3, 2)).collect();
+        assert_eq!(windows.len(), 3 * 3);
+        for (origin, window) in windows {
+            assert_eq!(window[Point::ZERO], origin);
+            assert_eq!(window.iter().count(), 6);
+            assert_eq!(window.to_grid(), grid.crop(window.rect()).unwrap());
+        }
+        assert_eq!(Grid::new(0, 0, 0).windows(Point::ONE).count(), 0);
+    }
+    #[test]
+    fn mutable_views_stay_inside() {
+        let mut grid = Grid::new(5, 4, 0);
+        let mut view = grid.view_mut(Rect::new(Point::new(1, 1), 3, 2)).unwrap();
+        view.fill(1);
+        view.view_mut(Rect::new(Point::new(2, 1), 1, 1)).unwrap().fill(2);
+        assert_eq!(view.get_mut_option(Point::new(3, 0)), None);
+        assert_eq!(view.as_view().to_grid().into_vec(), [1, 1, 1, 1, 1, 2]);
+        assert_eq!(grid.as_slice().iter().sum::<i32>(), 7);
+        assert_eq!(grid[Point::new(3, 2)], 2);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{Direction, Point};
+
+/// a position plus a heading, like a turtle or a guard on patrol
+/// ```
+/// use point_index::*;
+/// let mut walker = Walker::new(Point::new(0, 0), Direction::Right);
+/// walker.forward(3);
+/// walker.turn_right();
+/// let visited: Vec<Point> = walker.walk(2).collect();
+/// assert_eq!(visited, [Point::new(3, 1), Point::new(3, 2)]);
+/// assert_eq!(walker.pos, Point::new(3, 2));
+/// assert_eq!(walker.facing, Direction::Down);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Walker {
+    pub pos: Point,
+    pub facing: Direction,
+}
+
+impl Walker {
+    /// a walker standing at pos and facing the given direction
+    pub fn new(pos: Point, facing: Direction) -> Walker {
+        Walker { pos, facing }
+    }
+
+    /// the point directly in front of the walker
+    pub fn ahead(&self) -> Point {
+        self.pos + self.facing
+    }
+
+    /// moves n steps in the direction the walker is facing, negative n moves backwards
+    pub fn forward(&mut self, n: isize) {
+        self.pos += self.facing * n;
+    }
+
+    /// turns 90 degrees counter clockwise without moving
+    pub fn turn_left(&mut self) {
+        self.facing = self.facing.turn_left();
+    }
+
+    /// turns 90 degrees clockwise without moving
+    pub fn turn_right(&mut self) {
+        self.facing = self.facing.turn_right();
+    }
+
+    /// turns to face the opposite direction without moving
+    pub fn turn_around(&mut self) {
+        self.facing = self.facing.opposite();
+    }
+
+    /// moves n steps forward one at a time, yielding each point the walker stands on after a
+    /// step. the walker only moves as far as the iterator is driven
+    pub fn walk(&mut self, n: usize) -> impl Iterator<Item = Point> + '_ {
+        (0..n).map(move |_| {
+            self.forward(1);
+            self.pos
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patrol_square() {
+        let start = Point::new(5, 5);
+        let mut walker = Walker::new(start, Direction::Up);
+        let mut visited = Vec::new();
+        for _ in 0..4 {
+            visited.extend(walker.walk(2));
+            walker.turn_right();
+        }
+        assert_eq!(visited.len(), 8);
+        assert_eq!(walker, Walker::new(start, Direction::Up));
+    }
+    #[test]
+    fn lazy_walk() {
+        let mut walker = Walker::new(Point::ZERO, Direction::Left);
+        assert_eq!(walker.walk(10).take_while(|p| p.x > -3).count(), 2);
+        assert_eq!(walker.pos, Point::new_isize(-3, 0));
+        walker.turn_around();
+        walker.forward(-1);
+        assert_eq!(walker.ahead(), Point::new_isize(-3, 0));
+    }
+}
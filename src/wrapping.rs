@@ -0,0 +1,114 @@
+use std::ops::{Index, IndexMut};
+use crate::{Get, Grid, Point, Set};
+
+/// wraps a grid so every point is taken modulo its dimensions, the grid behaves like a torus
+/// where walking off one edge comes back in on the opposite one
+/// ```
+/// use point_index::*;
+/// let map: Grid<char> = "ab\ncd".parse().unwrap();
+/// let mut tiled = WrappingGrid::new(map);
+/// assert_eq!(tiled[Point::new(2, 0)], 'a');
+/// assert_eq!(tiled[Point::new_isize(-1, -1)], 'd');
+/// tiled[Point::new(5, 4)] = 'B';
+/// assert_eq!(tiled.inner()[Point::new(1, 0)], 'B');
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct WrappingGrid<T> {
+    grid: Grid<T>,
+}
+
+impl<T> WrappingGrid<T> {
+    /// wraps grid, an empty grid can't be indexed into but get_option returns None
+    pub fn new(grid: Grid<T>) -> WrappingGrid<T> {
+        WrappingGrid { grid }
+    }
+
+    /// the wrapped grid
+    pub fn inner(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    /// the wrapped grid, mutably
+    pub fn inner_mut(&mut self) -> &mut Grid<T> {
+        &mut self.grid
+    }
+
+    /// gives back the wrapped grid
+    pub fn into_inner(self) -> Grid<T> {
+        self.grid
+    }
+
+    /// the cell of the inner grid that point lands on, None if the grid has no cells
+    /// ```
+    /// use point_index::*;
+    /// let tiled = WrappingGrid::new(Grid::new(3, 2, 0));
+    /// assert_eq!(tiled.wrap(Point::new_isize(-1, 5)), Some(Point::new(2, 1)));
+    /// assert_eq!(WrappingGrid::new(Grid::new(0, 2, 0)).wrap(Point::ZERO), None);
+    /// ```
+    pub fn wrap(&self, point: Point) -> Option<Point> {
+        (self.grid.width > 0 && self.grid.height > 0).then(|| point.wrap(self.grid.width, self.grid.height))
+    }
+}
+
+impl<T> From<Grid<T>> for WrappingGrid<T> {
+    fn from(grid: Grid<T>) -> WrappingGrid<T> {
+        WrappingGrid::new(grid)
+    }
+}
+
+impl<T> Index<Point> for WrappingGrid<T> {
+    type Output = T;
+
+    fn index(&self, index: Point) -> &T {
+        &self.grid[self.wrap(index).expect("can't index into an empty grid")]
+    }
+}
+
+impl<T> IndexMut<Point> for WrappingGrid<T> {
+    fn index_mut(&mut self, index: Point) -> &mut T {
+        let point = self.wrap(index).expect("can't index into an empty grid");
+        &mut self.grid[point]
+    }
+}
+
+/// only returns None if the grid has no cells
+impl<T> Get<Point> for WrappingGrid<T> {
+    type Output = T;
+
+    fn get_option(&self, point: Point) -> Option<&T> {
+        self.grid.get_option(self.wrap(point)?)
+    }
+
+    fn get_mut_option(&mut self, point: Point) -> Option<&mut T> {
+        let point = self.wrap(point)?;
+        self.grid.get_mut_option(point)
+    }
+}
+
+/// only returns None if the grid has no cells
+impl<T> Set<Point> for WrappingGrid<T> {
+    type Output = T;
+
+    fn set(&mut self, point: Point, value: T) -> Option<T> {
+        let point = self.wrap(point)?;
+        self.grid.set(point, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_point_lands_inside() {
+        let mut tiled = WrappingGrid::from(Grid::from_fn(3, 2, |p| p));
+        for point in Point::new_isize(-7, -7).to(Point::new(7, 7)) {
+            let wrapped = tiled.wrap(point).unwrap();
+            assert_eq!(tiled[point], wrapped);
+            assert_eq!(tiled.get_option(point), Some(&wrapped));
+        }
+        assert_eq!(tiled.set(Point::new(3, 2), Point::ONE), Some(Point::ZERO));
+        assert_eq!(tiled.into_inner()[Point::ZERO], Point::ONE);
+        assert_eq!(WrappingGrid::new(Grid::new(0, 0, 0)).get_option(Point::ZERO), None);
+    }
+}